@@ -0,0 +1,102 @@
+#[macro_use]
+extern crate criterion;
+use criterion::black_box;
+use criterion::Criterion;
+
+extern crate mersenne_ifma;
+use mersenne_ifma::fourq::constants::*;
+use mersenne_ifma::fourq::*;
+
+fn variable_base_mul(c: &mut Criterion) {
+    c.bench_function("FourQ variable-base mul", |b| {
+        let k = Scalar::random(&mut rand::thread_rng());
+        b.iter(|| black_box(FOURQ_GENERATOR).mul(black_box(&k)));
+    });
+}
+
+fn fixed_base_mul(c: &mut Criterion) {
+    c.bench_function("FourQ fixed-base mul", |b| {
+        let k = Scalar::random(&mut rand::thread_rng());
+        b.iter(|| FOURQ_BASEPOINT_TABLE.mul(black_box(&k)));
+    });
+}
+
+fn basepoint_table_create(c: &mut Criterion) {
+    c.bench_function("FourQ BasepointTable::create", |b| {
+        b.iter(|| BasepointTable::create(black_box(&FOURQ_GENERATOR)));
+    });
+}
+
+fn vartime_double_scalar_mul(c: &mut Criterion) {
+    c.bench_function("FourQ vartime double-scalar mul", |b| {
+        let mut rng = rand::thread_rng();
+        let point = FOURQ_GENERATOR.mul(&Scalar::random(&mut rng));
+        let k1 = Scalar::random(&mut rng);
+        let k2 = Scalar::random(&mut rng);
+        b.iter(|| {
+            EdwardsPoint::vartime_double_scalar_mul_basepoint(
+                black_box(&k1),
+                black_box(&point),
+                black_box(&k2),
+            )
+        });
+    });
+}
+
+const MULTISCALAR_SIZES: [usize; 3] = [16, 256, 4096];
+
+fn multiscalar_inputs(n: usize) -> (Vec<Scalar>, Vec<EdwardsPoint>) {
+    let mut rng = rand::thread_rng();
+    let scalars = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+    let points = (0..n)
+        .map(|_| FOURQ_BASEPOINT_TABLE.mul(&Scalar::random(&mut rng)))
+        .collect();
+    (scalars, points)
+}
+
+fn multiscalar_mul(c: &mut Criterion) {
+    c.bench_function_over_inputs(
+        "FourQ multiscalar mul",
+        |b, &&n| {
+            let (scalars, points) = multiscalar_inputs(n);
+            b.iter(|| EdwardsPoint::multiscalar_mul(black_box(&scalars), black_box(&points)));
+        },
+        &MULTISCALAR_SIZES,
+    );
+}
+
+fn vartime_multiscalar_mul(c: &mut Criterion) {
+    c.bench_function_over_inputs(
+        "FourQ vartime multiscalar mul",
+        |b, &&n| {
+            let (scalars, points) = multiscalar_inputs(n);
+            b.iter(|| {
+                EdwardsPoint::vartime_multiscalar_mul(black_box(&scalars), black_box(&points))
+            });
+        },
+        &MULTISCALAR_SIZES,
+    );
+}
+
+criterion_group!{
+    name = mul_benches;
+    config = Criterion::default();
+    targets =
+    variable_base_mul,
+    fixed_base_mul,
+    basepoint_table_create,
+    vartime_double_scalar_mul,
+}
+
+criterion_group!{
+    name = multiscalar_benches;
+    config = Criterion::default().sample_size(10);
+    targets =
+    multiscalar_mul,
+    vartime_multiscalar_mul,
+}
+
+criterion_main!{
+    mul_benches,
+    multiscalar_benches,
+}
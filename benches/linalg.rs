@@ -0,0 +1,99 @@
+#[macro_use]
+extern crate criterion;
+use criterion::black_box;
+use criterion::Criterion;
+
+extern crate mersenne_ifma;
+use mersenne_ifma::serial::*;
+
+// These compare `linalg` with the serial loop.  They use whichever
+// vector backend the build targets, so build with AVX2 or IFMA enabled
+// to measure it; otherwise both sides are serial.
+
+fn elements(n: usize) -> Vec<F127> {
+    (0..n as u128)
+        .map(|i| F127::from(i.wrapping_mul(0x5851f42d4c957f2d14057b7ef767814f) >> 2))
+        .collect()
+}
+
+fn serial_dot(a: &[F127], b: &[F127]) -> F127 {
+    a.iter()
+        .zip(b.iter())
+        .fold(F127::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+fn linalg_dot(c: &mut Criterion) {
+    let a = elements(1024);
+    let b: Vec<F127> = a.iter().rev().copied().collect();
+
+    let (serial_a, serial_b) = (a.clone(), b.clone());
+    c.bench_function("serial dot (1024)", move |bench| {
+        bench.iter(|| serial_dot(black_box(&serial_a), black_box(&serial_b)))
+    });
+
+    c.bench_function("linalg::dot (1024)", move |bench| {
+        bench.iter(|| mersenne_ifma::linalg::dot(black_box(&a), black_box(&b)))
+    });
+}
+
+fn linalg_mat_vec(c: &mut Criterion) {
+    const N: usize = 64;
+    let m = elements(N * N);
+    let v = elements(N);
+
+    let (serial_m, serial_v) = (m.clone(), v.clone());
+    c.bench_function("serial mat_vec (64x64)", move |bench| {
+        let mut out = vec![F127::zero(); N];
+        bench.iter(|| {
+            for (r, z) in out.iter_mut().enumerate() {
+                *z = serial_dot(&serial_m[r * N..(r + 1) * N], black_box(&serial_v));
+            }
+        })
+    });
+
+    c.bench_function("linalg::mat_vec (64x64)", move |bench| {
+        let mut out = vec![F127::zero(); N];
+        bench.iter(|| mersenne_ifma::linalg::mat_vec(&m, black_box(&v), &mut out))
+    });
+}
+
+fn linalg_mat_mul(c: &mut Criterion) {
+    const N: usize = 32;
+    let a = elements(N * N);
+    let b: Vec<F127> = a.iter().rev().copied().collect();
+
+    let (serial_a, serial_b) = (a.clone(), b.clone());
+    c.bench_function("serial mat_mul (32x32)", move |bench| {
+        let mut out = vec![F127::zero(); N * N];
+        bench.iter(|| {
+            let (a, b) = (black_box(&serial_a), black_box(&serial_b));
+            for r in 0..N {
+                for col in 0..N {
+                    let mut z = F127::zero();
+                    for k in 0..N {
+                        z = z + a[r * N + k] * b[k * N + col];
+                    }
+                    out[r * N + col] = z;
+                }
+            }
+        })
+    });
+
+    c.bench_function("linalg::mat_mul (32x32)", move |bench| {
+        let mut out = vec![F127::zero(); N * N];
+        bench.iter(|| mersenne_ifma::linalg::mat_mul(black_box(&a), black_box(&b), N, &mut out))
+    });
+}
+
+criterion_group!{
+    name = linalg_benches;
+    config = Criterion::default();
+    targets =
+    linalg_dot,
+    linalg_mat_vec,
+    linalg_mat_mul,
+}
+
+criterion_main!{
+    linalg_benches,
+}
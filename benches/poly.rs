@@ -0,0 +1,75 @@
+#[macro_use]
+extern crate criterion;
+use criterion::black_box;
+use criterion::Criterion;
+
+extern crate mersenne_ifma;
+use mersenne_ifma::serial::*;
+
+// These compare `poly` with the serial loop.  They use whichever
+// vector backend the build targets, so build with AVX2 or IFMA enabled
+// to measure it; otherwise both sides are serial.
+
+/// Serial Horner evaluation, for comparison.
+fn horner(coeffs: &[F127], x: F127) -> F127 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F127::zero(), |acc, &c| acc * x + c)
+}
+
+fn poly_coefficients(n: usize) -> Vec<F127> {
+    (0..n as u128)
+        .map(|i| F127::from(i.wrapping_mul(0x5851f42d4c957f2d14057b7ef767814f) >> 2))
+        .collect()
+}
+
+fn poly_evaluate(c: &mut Criterion) {
+    let coeffs = poly_coefficients(256);
+    let x = F127::from(101054725971136791246222244709531340474u128);
+
+    let serial_coeffs = coeffs.clone();
+    c.bench_function("serial Horner (256 coefficients)", move |b| {
+        b.iter(|| horner(&serial_coeffs, black_box(x)))
+    });
+
+    c.bench_function("poly::evaluate (256 coefficients)", move |b| {
+        b.iter(|| mersenne_ifma::poly::evaluate(&coeffs, black_box(x)))
+    });
+}
+
+fn poly_evaluate4(c: &mut Criterion) {
+    let coeffs = poly_coefficients(256);
+    let x = F127::from(101054725971136791246222244709531340474u128);
+    let y = F127::from(38188712660835962328561942614081743514u128);
+    let xs = (x, y, x + y, x * y);
+
+    let serial_coeffs = coeffs.clone();
+    c.bench_function("serial Horner x4 (256 coefficients)", move |b| {
+        b.iter(|| {
+            let xs = black_box(xs);
+            (
+                horner(&serial_coeffs, xs.0),
+                horner(&serial_coeffs, xs.1),
+                horner(&serial_coeffs, xs.2),
+                horner(&serial_coeffs, xs.3),
+            )
+        })
+    });
+
+    c.bench_function("poly::evaluate4 (256 coefficients)", move |b| {
+        b.iter(|| mersenne_ifma::poly::evaluate4(&coeffs, black_box(xs)))
+    });
+}
+
+criterion_group!{
+    name = poly_benches;
+    config = Criterion::default();
+    targets =
+    poly_evaluate,
+    poly_evaluate4,
+}
+
+criterion_main!{
+    poly_benches,
+}
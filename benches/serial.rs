@@ -1,13 +1,8 @@
-#![feature(test)]
-
 #[macro_use]
 extern crate criterion;
-//use criterion::black_box;
+use criterion::black_box;
 use criterion::Criterion;
 
-extern crate test;
-use self::test::black_box;
-
 extern crate mersenne_ifma;
 use mersenne_ifma::serial::*;
 
@@ -39,27 +34,27 @@ fn prime_field_mul(c: &mut Criterion) {
 }
 
 fn ext_field_add(c: &mut Criterion) {
-    c.bench_function("F127Ext addition", |b| {
-        let x = F127Ext::from((2u128, 9u128));
-        let y = F127Ext::from((1u128, 8u128));
+    c.bench_function("ExtF127 addition", |b| {
+        let x = ExtF127::from((2u128, 9u128));
+        let y = ExtF127::from((1u128, 8u128));
 
         b.iter(|| black_box(black_box(x) + black_box(y)));
     });
 }
 
 fn ext_field_sub(c: &mut Criterion) {
-    c.bench_function("F127Ext subtraction", |b| {
-        let x = F127Ext::from((2u128, 9u128));
-        let y = F127Ext::from((1u128, 8u128));
+    c.bench_function("ExtF127 subtraction", |b| {
+        let x = ExtF127::from((2u128, 9u128));
+        let y = ExtF127::from((1u128, 8u128));
 
         b.iter(|| black_box(black_box(x) - black_box(y)));
     });
 }
 
 fn ext_field_mul(c: &mut Criterion) {
-    c.bench_function("F127Ext multiplication", |b| {
-        let x = F127Ext::from((2u128, 9u128));
-        let y = F127Ext::from((1u128, 8u128));
+    c.bench_function("ExtF127 multiplication", |b| {
+        let x = ExtF127::from((2u128, 9u128));
+        let y = ExtF127::from((1u128, 8u128));
 
         b.iter(|| black_box(black_box(x) * black_box(y)));
     });
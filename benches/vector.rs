@@ -0,0 +1,124 @@
+#[macro_use]
+extern crate criterion;
+use criterion::black_box;
+use criterion::Criterion;
+
+extern crate mersenne_ifma;
+use mersenne_ifma::serial::*;
+use mersenne_ifma::vector::*;
+
+fn ext_table(size: usize) -> Vec<ExtF127x4> {
+    let x = ExtF127::from((2u128, 9u128));
+    let y = ExtF127::from((1u128, 8u128));
+    (0..size).map(|_| ExtF127x4::from((x, y, x, y))).collect()
+}
+
+fn ext_field_select(c: &mut Criterion) {
+    c.bench_function("ExtF127x4 select (8 entries)", |b| {
+        let table = ext_table(8);
+        b.iter(|| black_box(ExtF127x4::select(&table, black_box(5))));
+    });
+
+    c.bench_function("ExtF127x4 select (16 entries)", |b| {
+        let table = ext_table(16);
+        b.iter(|| black_box(ExtF127x4::select(&table, black_box(11))));
+    });
+}
+
+fn ext_field_select_signed(c: &mut Criterion) {
+    c.bench_function("ExtF127x4 select_signed (8 entries)", |b| {
+        let table = ext_table(8);
+        b.iter(|| black_box(ExtF127x4::select_signed(&table, black_box(-5))));
+    });
+
+    c.bench_function("ExtF127x4 select_signed (16 entries)", |b| {
+        let table = ext_table(16);
+        b.iter(|| black_box(ExtF127x4::select_signed(&table, black_box(-11))));
+    });
+}
+
+// Multiplication by a fixed operand is measured as a chain of
+// dependent multiplications, as in a formula reusing a curve constant,
+// so that the operand stays in registers.
+const MUL_CHAIN: usize = 16;
+
+fn prime_field_mul(c: &mut Criterion) {
+    let x = F127::from(101054725971136791246222244709531340474u128);
+    let y = F127::from(38188712660835962328561942614081743514u128);
+    let x_vec = F127x4::splat(x);
+    let y_vec = F127x4::splat(y);
+
+    c.bench_function("F127x4 mul (x16)", move |b| {
+        b.iter(|| {
+            let y = black_box(y_vec);
+            let mut z = black_box(x_vec);
+            for _ in 0..MUL_CHAIN {
+                z = z * y;
+            }
+            z
+        })
+    });
+
+    let y_pre = PrecomputedF127x4::new(y_vec);
+    c.bench_function("F127x4 mul (precomputed, x16)", move |b| {
+        b.iter(|| {
+            let y = black_box(y_pre);
+            let mut z = black_box(x_vec);
+            for _ in 0..MUL_CHAIN {
+                z = z * y;
+            }
+            z
+        })
+    });
+}
+
+fn ext_field_mul(c: &mut Criterion) {
+    let x = ExtF127::from((2u128, 9u128));
+    let y = ExtF127::from((1u128, 8u128));
+    let x_vec = ExtF127x4::splat(x);
+    let y_vec = ExtF127x4::splat(y);
+
+    c.bench_function("ExtF127x4 mul (x16)", move |b| {
+        b.iter(|| {
+            let y = black_box(y_vec);
+            let mut z = black_box(x_vec);
+            for _ in 0..MUL_CHAIN {
+                z = z * y;
+            }
+            z
+        })
+    });
+
+    let y_pre = PrecomputedExtF127x4::new(y_vec);
+    c.bench_function("ExtF127x4 mul (precomputed, x16)", move |b| {
+        b.iter(|| {
+            let y = black_box(y_pre);
+            let mut z = black_box(x_vec);
+            for _ in 0..MUL_CHAIN {
+                z = z * y;
+            }
+            z
+        })
+    });
+}
+
+criterion_group!{
+    name = mul_benches;
+    config = Criterion::default();
+    targets =
+    prime_field_mul,
+    ext_field_mul,
+}
+
+criterion_group!{
+    name = lookup_benches;
+    config = Criterion::default();
+    targets =
+    ext_field_select,
+    ext_field_select_signed,
+}
+
+criterion_main!{
+    mul_benches,
+    lookup_benches,
+}
@@ -0,0 +1,202 @@
+//! Slice-level batch arithmetic.
+//!
+//! These functions apply a field operation elementwise to slices of
+//! serial field elements.  When the vector backend is available, the
+//! inputs are transposed into four-lane vectors and processed four
+//! elements at a time; any ragged tail (and everything, when the
+//! vector backend is not available) is handled with the serial
+//! implementation.
+//!
+//! # Panics
+//!
+//! All of these functions panic if the input and output slices have
+//! different lengths.
+
+use crate::serial::{ExtF127, F127};
+#[cfg(target_feature = "avx2")]
+use crate::vector::{ExtF127x4, F127x4};
+
+/// Compute `out[i] = vector(a[i], b[i])`, four lanes at a time, falling
+/// back to `serial(a[i], b[i])` for the remainder.
+macro_rules! zip_with {
+    ($a:expr, $b:expr, $out:expr, |$x:ident: $V:ty, $y:ident| $vector:expr, $serial:expr) => {{
+        let (a, b, out) = ($a, $b, $out);
+        assert_eq!(a.len(), out.len(), "input and output lengths differ");
+        assert_eq!(b.len(), out.len(), "input and output lengths differ");
+
+        #[allow(unused_mut)]
+        let mut i = 0;
+
+        #[cfg(target_feature = "avx2")]
+        while i + 4 <= out.len() {
+            let $x: $V = (a[i], a[i + 1], a[i + 2], a[i + 3]).into();
+            let $y: $V = (b[i], b[i + 1], b[i + 2], b[i + 3]).into();
+            let z: (_, _, _, _) = $vector.into();
+            out[i] = z.0;
+            out[i + 1] = z.1;
+            out[i + 2] = z.2;
+            out[i + 3] = z.3;
+            i += 4;
+        }
+
+        for j in i..out.len() {
+            let ($x, $y) = (a[j], b[j]);
+            out[j] = $serial;
+        }
+    }};
+}
+
+/// Compute `out[i] = vector(a[i])`, four lanes at a time, falling back
+/// to `serial(a[i])` for the remainder.
+macro_rules! map {
+    ($a:expr, $out:expr, |$x:ident: $V:ty| $vector:expr, $serial:expr) => {{
+        let (a, out) = ($a, $out);
+        assert_eq!(a.len(), out.len(), "input and output lengths differ");
+
+        #[allow(unused_mut)]
+        let mut i = 0;
+
+        #[cfg(target_feature = "avx2")]
+        while i + 4 <= out.len() {
+            let $x: $V = (a[i], a[i + 1], a[i + 2], a[i + 3]).into();
+            let z: (_, _, _, _) = $vector.into();
+            out[i] = z.0;
+            out[i + 1] = z.1;
+            out[i + 2] = z.2;
+            out[i + 3] = z.3;
+            i += 4;
+        }
+
+        for j in i..out.len() {
+            let $x = a[j];
+            out[j] = $serial;
+        }
+    }};
+}
+
+/// Compute `out[i] = a[i] * b[i]`.
+pub fn mul(a: &[F127], b: &[F127], out: &mut [F127]) {
+    zip_with!(a, b, out, |x: F127x4, y| x * y, x * y)
+}
+
+/// Compute `out[i] = a[i] + b[i]`.
+pub fn add(a: &[F127], b: &[F127], out: &mut [F127]) {
+    zip_with!(a, b, out, |x: F127x4, y| x + y, x + y)
+}
+
+/// Compute `out[i] = a[i] * a[i]`.
+pub fn square(a: &[F127], out: &mut [F127]) {
+    map!(a, out, |x: F127x4| x * x, x * x)
+}
+
+/// Compute `out[i] = a[i] * s`.
+pub fn mul_scalar(a: &[F127], s: F127, out: &mut [F127]) {
+    #[cfg(target_feature = "avx2")]
+    let s_vec = F127x4::splat(s);
+    map!(a, out, |x: F127x4| x * s_vec, x * s)
+}
+
+/// Compute `out[i] = a[i] * b[i]` in the extension field.
+pub fn mul_ext(a: &[ExtF127], b: &[ExtF127], out: &mut [ExtF127]) {
+    zip_with!(a, b, out, |x: ExtF127x4, y| x * y, x * y)
+}
+
+/// Compute `out[i] = a[i] + b[i]` in the extension field.
+pub fn add_ext(a: &[ExtF127], b: &[ExtF127], out: &mut [ExtF127]) {
+    zip_with!(a, b, out, |x: ExtF127x4, y| x + y, x + y)
+}
+
+/// Compute `out[i] = a[i] * a[i]` in the extension field.
+pub fn square_ext(a: &[ExtF127], out: &mut [ExtF127]) {
+    map!(a, out, |x: ExtF127x4| x * x, x * x)
+}
+
+/// Compute `out[i] = a[i] * s` in the extension field.
+pub fn mul_scalar_ext(a: &[ExtF127], s: ExtF127, out: &mut [ExtF127]) {
+    #[cfg(target_feature = "avx2")]
+    let s_vec = ExtF127x4::splat(s);
+    map!(a, out, |x: ExtF127x4| x * s_vec, x * s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serial::elements;
+
+    fn ext_elements(n: usize, seed: u128) -> Vec<ExtF127> {
+        let re = elements(n, seed);
+        let im = elements(n, seed + 1);
+        re.into_iter().zip(im).map(|(a, b)| ExtF127(a, b)).collect()
+    }
+
+    // Cover the empty slice, a ragged tail, and several full vectors.
+    const LENGTHS: [usize; 6] = [0, 1, 3, 4, 7, 13];
+
+    #[test]
+    fn prime_field_ops_match_serial() {
+        for &n in &LENGTHS {
+            let a = elements(n, 1);
+            let b = elements(n, 2);
+            let s = elements(1, 3)[0];
+            let mut out = vec![F127::zero(); n];
+
+            mul(&a, &b, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] * b[i]);
+            }
+
+            add(&a, &b, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] + b[i]);
+            }
+
+            square(&a, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] * a[i]);
+            }
+
+            mul_scalar(&a, s, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] * s);
+            }
+        }
+    }
+
+    #[test]
+    fn ext_field_ops_match_serial() {
+        for &n in &LENGTHS {
+            let a = ext_elements(n, 4);
+            let b = ext_elements(n, 6);
+            let s = ext_elements(1, 8)[0];
+            let mut out = vec![ExtF127(F127::zero(), F127::zero()); n];
+
+            mul_ext(&a, &b, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] * b[i]);
+            }
+
+            add_ext(&a, &b, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] + b[i]);
+            }
+
+            square_ext(&a, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] * a[i]);
+            }
+
+            mul_scalar_ext(&a, s, &mut out);
+            for i in 0..n {
+                assert_eq!(out[i], a[i] * s);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "lengths differ")]
+    fn mismatched_lengths_panic() {
+        let a = elements(4, 1);
+        let mut out = vec![F127::zero(); 5];
+        square(&a, &mut out);
+    }
+}
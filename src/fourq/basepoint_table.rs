@@ -0,0 +1,140 @@
+//! Fixed-base scalar multiplication with a precomputed comb.
+//!
+//! This is the modified LSB-set comb used by FourQlib, with 5 rows of
+//! 50 columns: a scalar is recoded by `recode_comb` into one signed
+//! digit per column, and column \\(j\\) stands for the point
+//! $$
+//! \pm 2^j \left( 1 + \sum\_{t=1}^{4} d\_{j,t} 2^{50t} \right) P,
+//! \qquad d\_{j,t} \in \\{0, 1\\}.
+//! $$
+//! The columns are split into 5 blocks of 10, and block \\(l\\) has
+//! its own table of the 16 points
+//! \\(2^{10l} (1 + \sum d\_t 2^{50t}) P\\), so that a multiplication
+//! takes 10 doublings and 50 additions, each addition using a
+//! constant-time lookup.  The tables are normalized to \\(Z = 1\\).
+
+#![allow(non_snake_case)]
+
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+use crate::fourq::edwards::CachedPoint;
+use crate::fourq::scalar::recode_comb;
+use crate::fourq::{EdwardsPoint, Scalar};
+use crate::serial::ExtF127;
+
+/// A table of precomputed multiples of a point, for fixed-base scalar
+/// multiplication.
+///
+/// The table for the standard generator is provided as
+/// `constants::FOURQ_BASEPOINT_TABLE`.
+#[derive(Clone)]
+pub struct BasepointTable(pub(crate) [[CachedPoint; 16]; 5]);
+
+impl BasepointTable {
+    /// Create a table of multiples of `basepoint`, which must lie in
+    /// the subgroup of order \\(N\\).
+    pub fn create(basepoint: &EdwardsPoint) -> BasepointTable {
+        let mut table = [[CachedPoint::identity(); 16]; 5];
+        let mut B = *basepoint;
+        for block in table.iter_mut() {
+            let mut points = [B; 16];
+            let mut row = B;
+            for t in 0..4 {
+                for _ in 0..50 {
+                    row = row.double();
+                }
+                for u in 0..(1 << t) {
+                    points[u + (1 << t)] = points[u] + row;
+                }
+            }
+            for (entry, P) in block.iter_mut().zip(points.iter()) {
+                *entry = normalize(P);
+            }
+
+            for _ in 0..10 {
+                B = B.double();
+            }
+        }
+        BasepointTable(table)
+    }
+
+    /// Compute \\([k] B\\) for the basepoint \\(B\\) of this table, in
+    /// constant time.
+    pub fn mul(&self, k: &Scalar) -> EdwardsPoint {
+        let (digits, signs) = recode_comb(k);
+        let select = |l: usize, j: usize| {
+            let mut t = CachedPoint::identity();
+            for (u, P) in self.0[l].iter().enumerate() {
+                t.conditional_assign(P, (u as u8).ct_eq(&digits[j]));
+            }
+            t.conditional_negate(Choice::from((signs[j] as u8) >> 7));
+            t
+        };
+
+        let mut Q = EdwardsPoint::identity();
+        for r in (0..10).rev() {
+            Q = Q.double();
+            for l in 0..5 {
+                Q = Q + select(l, 10 * l + r);
+            }
+        }
+        Q
+    }
+}
+
+/// Convert a point to cached form with \\(Z = 1\\).
+fn normalize(P: &EdwardsPoint) -> CachedPoint {
+    let Zinv = P.Z.invert();
+    let x = P.X * Zinv;
+    let y = P.Y * Zinv;
+    CachedPoint::from(EdwardsPoint {
+        X: x,
+        Y: y,
+        Z: ExtF127::one(),
+        T: x * y,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fourq::constants::{FOURQ_BASEPOINT_TABLE, FOURQ_GENERATOR};
+
+    #[test]
+    fn static_table_matches_created_table() {
+        let table = BasepointTable::create(&FOURQ_GENERATOR);
+        for (block, static_block) in table.0.iter().zip(FOURQ_BASEPOINT_TABLE.0.iter()) {
+            for (P, Q) in block.iter().zip(static_block.iter()) {
+                assert!(bool::from(P.YpX.ct_eq(&Q.YpX)));
+                assert!(bool::from(P.YmX.ct_eq(&Q.YmX)));
+                assert!(bool::from(P.Z2.ct_eq(&Q.Z2)));
+                assert!(bool::from(P.T2d.ct_eq(&Q.T2d)));
+            }
+        }
+    }
+
+    #[test]
+    fn mul_matches_variable_base() {
+        let mut rng = rand::thread_rng();
+        let P = FOURQ_GENERATOR.mul(&Scalar::random(&mut rng));
+        let table = BasepointTable::create(&P);
+        for _ in 0..16 {
+            let k = Scalar::random(&mut rng);
+            assert_eq!(FOURQ_BASEPOINT_TABLE.mul(&k), FOURQ_GENERATOR.mul(&k));
+            assert_eq!(table.mul(&k), P.mul(&k));
+        }
+    }
+
+    #[test]
+    fn mul_edge_cases() {
+        let G = FOURQ_GENERATOR;
+        let minus_one = -Scalar::one();
+
+        let table = &FOURQ_BASEPOINT_TABLE;
+        assert_eq!(table.mul(&Scalar::zero()), EdwardsPoint::identity());
+        assert_eq!(table.mul(&Scalar::one()), G);
+        assert_eq!(table.mul(&Scalar::from(2)), G.double());
+        assert_eq!(table.mul(&minus_one), -G);
+        assert_eq!(table.mul(&(minus_one - Scalar::one())), -G.double());
+    }
+}
@@ -0,0 +1,2224 @@
+//! Constants for the FourQ curve.
+
+use crate::fourq::edwards::CachedPoint;
+use crate::fourq::window::NafLookupTable6;
+use crate::fourq::{BasepointTable, EdwardsPoint};
+use crate::serial::{ExtF127, F127};
+
+/// The Edwards curve parameter \\(d\\).
+pub(crate) const EDWARDS_D: ExtF127 = ExtF127(
+    F127(0x00000000000000e40000000000000142),
+    F127(0x5e472f846657e0fcb3821488f1fc0c8d),
+);
+
+/// The Edwards curve parameter \\(2d\\), used by the addition formulas.
+pub(crate) const EDWARDS_D2: ExtF127 = ExtF127(
+    F127(0x00000000000001c80000000000000284),
+    F127(0x3c8e5f08ccafc1f967042911e3f8191b),
+);
+
+/// The standard FourQ generator, of prime order \\(N\\).
+pub const FOURQ_GENERATOR: EdwardsPoint = EdwardsPoint {
+    X: ExtF127(
+        F127(0x1a3472237c2fb305286592ad7b3833aa),
+        F127(0x1e1f553f2878aa9c96869fb360ac77f6),
+    ),
+    Y: ExtF127(
+        F127(0x0e3fee9ba120785ab924a2462bcbb287),
+        F127(0x6e1c4af8630e024249a7c344844c8b5c),
+    ),
+    Z: ExtF127(F127(1), F127(0)),
+    T: ExtF127(
+        F127(0x35bfa1947fb0913e894ba36ee8cee416),
+        F127(0x7bfb41a38e7076ac673c574d296cd8d0),
+    ),
+};
+
+/// A precomputed table of multiples of `FOURQ_GENERATOR`, for
+/// fixed-base scalar multiplication.
+pub static FOURQ_BASEPOINT_TABLE: BasepointTable = BasepointTable([
+    [
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x287460bf1d502b5fe18a34f3a703e631),
+                F127(0x0c3ba0378b86acdee02e62f7e4f90353),
+            ),
+            YmX: ExtF127(
+                F127(0x740b7c7824f0c55590bf0f98b0937edc),
+                F127(0x4ffcf5b93a9557a5b321239123a01366),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5948d137556c97c6297afccbabda42bb),
+                F127(0x0caf2b720a341f27a8189a393330684c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5546128188dd12a83a8ba018fd188787),
+                F127(0x1baeeaf8b84d2049b0b3cc33c09f9b77),
+            ),
+            YmX: ExtF127(
+                F127(0x18f7cd12e1a6f789006425a611faf900),
+                F127(0x448e05eeace7b6eb6dccf09a12556066),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6d911dcb2957bdb4bf2f33689d2829b0),
+                F127(0x06c54305babee5019f2353dbdc3c03ee),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x72963058648a364d2eaf45713dafa125),
+                F127(0x4f41c7f8bfe2b06961b7771f9d313ef2),
+            ),
+            YmX: ExtF127(
+                F127(0x4d33858644330a42408623ae599790ac),
+                F127(0x74df72e0e598e114fc5696649cdd7487),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x076bd4115fe4b0d8c9a06325913c110b),
+                F127(0x249240147cee3a0876619e65d6bff3d9),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x28aac8a28829f706d695b96148965a73),
+                F127(0x441ca9e89f03e00e41f1c05329f7a57b),
+            ),
+            YmX: ExtF127(
+                F127(0x58f28cafc832b7f4e1aa38ab8bf7241e),
+                F127(0x34b6d106284e863ecadaf8b8fa5400c6),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6dbe7790017d9c49f5498cab3af15097),
+                F127(0x6371925bf23ae00663bf76a81448e8bc),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4ede70eed68056abc5e2c721bded81fa),
+                F127(0x4752fd192f0a9aa88f3cd9b5b4975810),
+            ),
+            YmX: ExtF127(
+                F127(0x11ddf7d2c8468662318794eb1f734414),
+                F127(0x465575b37ab067702613b06f72b1a34e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x48894050790298ce40b9845f82638d2b),
+                F127(0x04f3560d2889b2fbbedb93a501b4f131),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x56f25ee54d92858a457dd875115b278b),
+                F127(0x078fca4187d7499692d4c1cdce0c977e),
+            ),
+            YmX: ExtF127(
+                F127(0x117b28853ddc2bf63bbb2ded76cc22a1),
+                F127(0x73079e25e0ea8a8f43f3767cb9c2baa2),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2e77721480d9ef920177992b5a15796d),
+                F127(0x258f176b7af7576dbe09883567372916),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7285925f9a7353a4308338fd6168391b),
+                F127(0x53259ee7423aeb51862c0fd04fe85114),
+            ),
+            YmX: ExtF127(
+                F127(0x1a4f1d661fa071fcfe0031a84b3b1a68),
+                F127(0x60185c1adf196a6a2ddd54168dc928a7),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6062094b4dcffc0349809717dc6da9b4),
+                F127(0x4a4fe06f277148a0a41ea6fa05fa7e8d),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x419a928bccb117337bb253a9ee9e80f0),
+                F127(0x01b2d1ae972814bb84323be66a9a039e),
+            ),
+            YmX: ExtF127(
+                F127(0x54df1e20cc979dd7a7588584d3051231),
+                F127(0x4e36e9975fdf1a0f91d906fe3e2f22dd),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x3e5e31baeee13433d81871746b747634),
+                F127(0x4b852ad97cfe77c6e4da80979573baa3),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x283d719b2fe6ef88e08b346714418b9e),
+                F127(0x75acfcef11d2d5c8b7339d2de45c180b),
+            ),
+            YmX: ExtF127(
+                F127(0x0c54ac40a7134c4b8f40777a8c561876),
+                F127(0x6f357e5006a188bfb92e287d66baee08),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x747c45ef91dafd40c5903319ed1e6971),
+                F127(0x5dcb27edb3b3ef7dde4086a91d2f816e),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x51551f9f7096649843fdc46cfa1dd2ee),
+                F127(0x453455b3073fb07fb54534f761ed9bdc),
+            ),
+            YmX: ExtF127(
+                F127(0x679be25e758cf4dff24773e383cab70b),
+                F127(0x3dc9e5b8d6dc0f66da17edf2943eee29),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1e65315bc5a8537f56a50cba413fb75b),
+                F127(0x73c9d8c8f425252e5ff90242802c7213),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x534f84b3ed414f333c637b8633198c8f),
+                F127(0x5ed57e941cdf33afad313e72dedd6902),
+            ),
+            YmX: ExtF127(
+                F127(0x73b63dea344713f95a6fe01d2a57306e),
+                F127(0x2df8c6e49f1a18db39cb70570f1c2bf3),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x501ae7cbbebe9062661bc349677797e4),
+                F127(0x0372752811c01d515b52a88de8959643),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x378b317155554fc6010c57a2301bb928),
+                F127(0x5f0047b850d7db29f883fa4229a02cf1),
+            ),
+            YmX: ExtF127(
+                F127(0x0d030627a850a2bc4d247ae328402daa),
+                F127(0x6ec9686b2d6db089b4e65d9a88a443f5),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5c64e1d3f28d7600de202e08fea1d987),
+                F127(0x56392d36dd75334c157d17bef661bfb7),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x146d4f2d3d336afde25478d8bd19155c),
+                F127(0x2b185a9a6adf10c09bfbe00bf94e15e8),
+            ),
+            YmX: ExtF127(
+                F127(0x67997e1473101e80926527b3ed52ab7b),
+                F127(0x36f800c7fac99a7ab58f4ff4947cc541),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4372e43640bc697bd0302e32400456d9),
+                F127(0x75d25afac9a23cbf9144cabb4750d898),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x74db216617fc4b07794591767655cbfe),
+                F127(0x1d543b5908417b237057b2242566d0c9),
+            ),
+            YmX: ExtF127(
+                F127(0x352309fd8b6cc3ef19c280b444428783),
+                F127(0x4ec0671a23c019f437833d6ac068ae72),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x44fe1adff224efe39d9836e1a3d05bb5),
+                F127(0x2efec86835a14150a296bc3ce57efb4a),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x18cc07d3953cd2062fe19c09fb194bca),
+                F127(0x671aa756581abcee5bdff217c9c0b9e0),
+            ),
+            YmX: ExtF127(
+                F127(0x1b6f254937a0a3fee1cc33ae28f7d1a2),
+                F127(0x74b95636d588921151503d1665babb83),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1507ce189e2510bdbdb97ae4ea96f869),
+                F127(0x6a81765f05960929796e4d54fab93b13),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x3bdea532b245f6442e940521e5a833ed),
+                F127(0x64b94848ba6d4ed6bea76975ffd52693),
+            ),
+            YmX: ExtF127(
+                F127(0x71cf65da55639f259db52d0194e33ec7),
+                F127(0x12e4d13b6c62dc22ede73b1fdb5a8138),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x77a011d257b5fdd09d19b0c265185517),
+                F127(0x46844e151e3492d11fedc5caaecd84e4),
+            ),
+        },
+    ],
+    [
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5b3165c747e8f0997a423a31904220df),
+                F127(0x7802b556fc45595b1c665eeadf35e22e),
+            ),
+            YmX: ExtF127(
+                F127(0x17f2ab87957166ad85a2def4015bd2de),
+                F127(0x122a7ad1be408e6a19cf6d352060c1e5),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x20fb009d4d0adacf5b79bbc8645bf766),
+                F127(0x7041b4e90d420bde97526a272ba28538),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x3d398b66f0d242433b30113358dab057),
+                F127(0x1eae2409cd93809691a5999a03cd4708),
+            ),
+            YmX: ExtF127(
+                F127(0x171308378908196866dd6b604c36108c),
+                F127(0x34b06cb89704f1ca57cad6917125dcfd),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x698331198d544db9dcafe8e71f35abf2),
+                F127(0x200950e5559d2b6d6287676643af075b),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7473317142ac13a2d4f63fc3ecdd9074),
+                F127(0x2c20ffe0244378ba96b0030805319356),
+            ),
+            YmX: ExtF127(
+                F127(0x4ee327219997fcf64889511ad26ac01a),
+                F127(0x6b617fb4a6d0a6d715ffe6e70f0bf8ea),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x3c8269f0864682774916dca1c52f7324),
+                F127(0x4e480b4f915a542cc24210c4c837e04b),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x31a501de44fd84b2c5fef3b09a7fe35e),
+                F127(0x0ba7e03ca5cce5ab79f29e4940a407b9),
+            ),
+            YmX: ExtF127(
+                F127(0x46f4c7810e26dadca7a8b2058a74d8ea),
+                F127(0x44db55025495a81146171ace94a1128a),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4d4f172a43f306b27f889e1a4bf18d5c),
+                F127(0x6254775924d39aca33a99766bb1cffad),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1c544dd078d9211dd855230ec225136e),
+                F127(0x069af1dc949dd38212fe9969f63f63ba),
+            ),
+            YmX: ExtF127(
+                F127(0x63ae90924bbbb595305bcf40cfe5c256),
+                F127(0x09780cf39fc0043ee451097793b7de06),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x3ace8a6c77577a37827af8e7eb798871),
+                F127(0x561dc07aaacea92b79df061332e055ba),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6b85df83e0af53487e4422d9820d2673),
+                F127(0x35ead8e5157142bd1f151ac1ded8526b),
+            ),
+            YmX: ExtF127(
+                F127(0x5f2ea04d2594fde46da6ef6c33c79dd4),
+                F127(0x53b5401007b0331b91037d0cc027d5fa),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4463bd259ba94195810f198a3d4ba5a3),
+                F127(0x78711761d64349ce32b894acec2acf9e),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x409e4b3f535b6463253ae1b3f51fe211),
+                F127(0x19d2b1029c21336a3a236d10da5e49de),
+            ),
+            YmX: ExtF127(
+                F127(0x0942a31505190b192835f40436aadd90),
+                F127(0x3afe96c3ca8e1f9cc189131876828279),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x39e28db8625fd0919f1801b491230693),
+                F127(0x145155da729b280d9fab50355dd44c8e),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5a0faa1a8c2b6c68d3ccf8101d4d76d5),
+                F127(0x51052ce3f566c7733cc66c84cb54ea8a),
+            ),
+            YmX: ExtF127(
+                F127(0x7586118a01ccf0243bee14de65ae9ff5),
+                F127(0x35ff022d261d93d6089e791c896bf15e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4f1de98f95b7b8f6cd3ce13d8f7d1cf9),
+                F127(0x61ad9e3c23f6dd2951e68a2462dc41b4),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5d52fe073f9decf3584fea6480ebdb51),
+                F127(0x1dfa03c980b1696a9afe483eadf336d5),
+            ),
+            YmX: ExtF127(
+                F127(0x697bf55d361100ed55f73d47ff819a19),
+                F127(0x618c94467fce259fded4804446399419),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x07c935b98dd933c0f2597ff1f08ef50c),
+                F127(0x1e9a0d06af13148fbb758cbc78ded5f6),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x28396ca1962d4994879ce1457f4cd4db),
+                F127(0x1e570f3da4c527b1f5095a3dc57605c3),
+            ),
+            YmX: ExtF127(
+                F127(0x591ee376fdd01cce2af69a3904935787),
+                F127(0x5464d651b2f395d1f77b58df88bc8633),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6ce2df4bf65b6b28afbc096b1e9a86ae),
+                F127(0x6382011d8d2d66d03b3a828d2e9d3e08),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x50ddf70d3b6d56af94987ca64d3d193d),
+                F127(0x39208098bc5b1f928d5df67cc8ad15a9),
+            ),
+            YmX: ExtF127(
+                F127(0x323bbc87b86a7ba9ce99f520dfd5a4fb),
+                F127(0x56ffdcbdf2200055e13f88a8d803c789),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x70011566460c0c163aff0da31b24c72d),
+                F127(0x1c069bfeb7077bc276f7b7f53ac46a13),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6d73e34af088de3d8f47193ca14a3c36),
+                F127(0x5b404738b77f1ec8634b2bd9317d6634),
+            ),
+            YmX: ExtF127(
+                F127(0x054abbcaca546a46f34fabb71ca1cb1d),
+                F127(0x6971abbf958bdef1e8cdcadd08eda660),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1e158585b079b67c41338557dddb4eaf),
+                F127(0x53b36d32b3cea469d2270474cfa26068),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4668e92c5f73314e011523c16c543d08),
+                F127(0x04037d1aa713931abaef3ebe4117acd1),
+            ),
+            YmX: ExtF127(
+                F127(0x6b80cd55a44c157568e118e4e390c68d),
+                F127(0x5cc5475feee99ab27307ea8a5729c032),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x3f09157e5db3dcd834450e424c14ac75),
+                F127(0x27a899c54e652f8f62ce2b1b50588052),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4b4044ddd5813eec0acd039f2fc2a5ed),
+                F127(0x242551bce71d33a1c04d189e90a75958),
+            ),
+            YmX: ExtF127(
+                F127(0x02988820f809d815d95af96b51f87f05),
+                F127(0x2ef60745f4364b43b27f65f73b9483c5),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2b86c9b48756bb8acb66bdc93f4fb8b9),
+                F127(0x441e70184e6fe9aaf8ebdae09b9867a1),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x47d8d65a8b4d6992fdc2530330cc1289),
+                F127(0x1ca8693cc3bd99d58c03b6fa30ae74be),
+            ),
+            YmX: ExtF127(
+                F127(0x3da04764d9f4fff5699eb1511018f2a6),
+                F127(0x2fa911612cb857ff361720433d3aab59),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x48a219b933a5c619a4057da10c2f1cac),
+                F127(0x73f8895046a09dad42341020d15f0bc5),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4194771b368e622e1bad5312c67421b8),
+                F127(0x4b4564e45467f1c28cc71a79e44e0dff),
+            ),
+            YmX: ExtF127(
+                F127(0x391b71dcd75fbea97759f16aafe52093),
+                F127(0x023087545444130d2a1c0694ab4ef798),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x64e26f32d73361e74b7ae1ffcfaa1aa1),
+                F127(0x148cfa6feaecee158da47038bd0b54b9),
+            ),
+        },
+    ],
+    [
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x25d44ea8d31543de3756d4d479c2cc3d),
+                F127(0x2c2047033d27f37fd82c8bef26bb2c43),
+            ),
+            YmX: ExtF127(
+                F127(0x77943117a3383b7d5bd33d9837dad260),
+                F127(0x3c7c41272a225bf212071d697ea583f2),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5d61030c68b6370492ebbdfaf1f03ad3),
+                F127(0x12404b34771a3636ca6e2853baee75d1),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2bd261916f9be3b0be13c46326667e4f),
+                F127(0x74520d8a1794cb4886e3f8cbadc80f89),
+            ),
+            YmX: ExtF127(
+                F127(0x5cee741e1e53eb021e15c745024cf97e),
+                F127(0x625812961cc0862c8d088de0af99cda1),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x60bbc768c424f7a44313437321c0e934),
+                F127(0x37b8ea9f14a915b8aba71fbf3c10e143),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x74a08828ff77845c8d96ec65c40213ff),
+                F127(0x17e86671161c8706bedb7194daf607a3),
+            ),
+            YmX: ExtF127(
+                F127(0x68552ac494916f09aceb98e0524059cf),
+                F127(0x68442ebcdde21b704cd2971baf1b3c47),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6a6955d3635fa47a19629b8c0e867595),
+                F127(0x66dd3ef4fcf050c46fab45e0f2e393ad),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x14eb5b751b0bcf9cbb0b7abcfddc7df1),
+                F127(0x5c496f73fff0600a1cf79f9ca2fd411d),
+            ),
+            YmX: ExtF127(
+                F127(0x46c1016a2322d8a949648d8555426d70),
+                F127(0x609eb65209ddb633b57fdb870d9b6d4f),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x772fb5b5c8afaf27e70f9166bedc82c5),
+                F127(0x7f75b141112dbc8d79a294d9b0227a20),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5953d0aac48217b198d1c7f88e070020),
+                F127(0x267d1dc11e614c45e28253ebe15f33ff),
+            ),
+            YmX: ExtF127(
+                F127(0x4eaaab5c82fe5495be64f50ab99e2246),
+                F127(0x67d3786de6aa1b4d927d5ac07e60bed0),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x63d93844a35eea9ba71962bf0f6e2945),
+                F127(0x169c38d2eb28f5a1b34228c7d26640ac),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x71478457cdaa1e144b7972b33439dc22),
+                F127(0x669d8796e78fd4f15226e125ec1d58c7),
+            ),
+            YmX: ExtF127(
+                F127(0x327c62b55aebbecf750dd1aaaa44a07f),
+                F127(0x2ab3f95d01eb364e006b8e95b54fbd25),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2a1b9bd75a57e725fcbe5080c0d5e196),
+                F127(0x751cf4af849b7a731d2b2b6758139b5d),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x00cee3a4cb83a4bc164a7d2e337d00a5),
+                F127(0x053d899148d285023498e0366dbe28f9),
+            ),
+            YmX: ExtF127(
+                F127(0x4a99132208d68e7401665d64cab0fb69),
+                F127(0x1d34b0f9172122bbba44bbd4bd3f915d),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x08e7a43dd5334b605d114dc729e8a9f3),
+                F127(0x5cb7be1b80264f6228db8e9232f0f3e8),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x336ae7ccf7e3a1b29af2c78782508f23),
+                F127(0x573d2e1b2b8a68727fe2d4ee2dd194be),
+            ),
+            YmX: ExtF127(
+                F127(0x200bc1375b1f42433332ea3363b2ea36),
+                F127(0x42021fca53995c5e65c47c8c06b3260d),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x311fba6a23196d2c2f7e6cf49bb19946),
+                F127(0x61eeac142711b0dcc30c13b62be0d70d),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x70169bcbe6bd21d788526996597d35d4),
+                F127(0x2ade531472c1b94da0f1b2d0ad29a510),
+            ),
+            YmX: ExtF127(
+                F127(0x2d2a1794e85cdb3811e320dc189873e7),
+                F127(0x4b06d5b54525f6f7a0a8c453a6f621e3),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1d4216555d578730f42916691848ec1c),
+                F127(0x66dd9f39a1f3565ff8c60da7290a5b4e),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4291967a4a369ee455ac29d937b474a0),
+                F127(0x3d46e8900651c310918dacaa12e6bc89),
+            ),
+            YmX: ExtF127(
+                F127(0x16f62bf56da5ca39af055430a00e90b1),
+                F127(0x0d64dadf63fbbcd51a021c33488c51e6),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x3b3319d7dd74203a0918ece59dbfea7c),
+                F127(0x13b792dc908c59e61d88545b8b9fa90c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x321a5dbeb74bf1270a2d939a9c3d0979),
+                F127(0x22ec9ecafd26bc995e5947fff66d8470),
+            ),
+            YmX: ExtF127(
+                F127(0x593f56c0559dd846de17ca8293b10536),
+                F127(0x23c6b0fdf7448b1c1148373375485023),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x573e91962726ea70377904458a27804f),
+                F127(0x51ba082049f4f85e35e1b24f3235ac70),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5d29a21e3308e1dd4bc4918160d47194),
+                F127(0x50dbbd2f4f31d0fb7e15894b3e6e4e33),
+            ),
+            YmX: ExtF127(
+                F127(0x3418add21b634710ef248bd235a9c9de),
+                F127(0x7c8414ad9a08c99f96c7233a52363bd2),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5729021a1193579abc6acb4a54e6c05c),
+                F127(0x3d0b4ff9e17c2a730627c3e00b08fa1c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x75b27bb3bc7bfe48d507e8755990317f),
+                F127(0x7b9795fc1b706e4644a80f2c6ce651f5),
+            ),
+            YmX: ExtF127(
+                F127(0x75ade50ababffaa89de75bdefdf9a640),
+                F127(0x6f3ddcfcdd59ec6cce0ab116870889a0),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x291d1129ea28a0736e36833588de0674),
+                F127(0x706ef8f1ae854d76f8b8e53864884d61),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x01e45f1cc620f966137a8c6583753069),
+                F127(0x36d29eace3e89c54e28e1ff82f76c7ba),
+            ),
+            YmX: ExtF127(
+                F127(0x65e9c39e2bacb93783379f157f0b49cb),
+                F127(0x16e02f31ab7e2de59b323c45070cda3e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1fd7e207d6c2de0953bcf346635122b7),
+                F127(0x0cba06e8d0f0b4df3a5f5f94ea1e57ac),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1e7dc143dee1d80070b440c387a9c392),
+                F127(0x332870a017182d145498ba6d7239912b),
+            ),
+            YmX: ExtF127(
+                F127(0x2c2ce211245b2b4e6be306fc672d794c),
+                F127(0x268520fa9c5f727a109b722c8d2ba79f),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x736201eccbaea698515b300524fe78ee),
+                F127(0x32d8fd919c4418434608ac113210bf78),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x775437f798dc7459c9557e1b04b8f2d8),
+                F127(0x2e00ec5f3e7ad3041200f5585ba417f5),
+            ),
+            YmX: ExtF127(
+                F127(0x32270a93624876e4fc873d5f2b446288),
+                F127(0x2370d9fe925616bec646a47c08789b22),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x156468ceac1f5fb2430afa3619e671c4),
+                F127(0x31140e9017c0e58f3b84dec2f2417635),
+            ),
+        },
+    ],
+    [
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x0da75f5d64d864ac5c85f88ccb7443fa),
+                F127(0x1b79e10bad3336c3295ff44871b0fb84),
+            ),
+            YmX: ExtF127(
+                F127(0x4c1b198d0f9a1a23ffdf9942dd2977b3),
+                F127(0x74f66897f26d48d0ba778a24c112864e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4b98ce33ff7878b93fd5c06e867ab611),
+                F127(0x11665aa099ec5163f7db4dce75cb9165),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x265ec3dbb4eb509a2a498f16ae7118b9),
+                F127(0x36e62baab2e333853da4230668ce2c86),
+            ),
+            YmX: ExtF127(
+                F127(0x25bfb2fc411e887599507d4a79ab4478),
+                F127(0x23d341ae033d0466d7ac1ec933022ce1),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x23d0211ba2d73180d295b465e962bc00),
+                F127(0x1e767148de301514a03ccd7aff922d4d),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1c9fc2f343fc1e58c241ab36a894efab),
+                F127(0x53623e2285dd7015ca3b96562bd27a87),
+            ),
+            YmX: ExtF127(
+                F127(0x19265577096b42f9557411f01c219420),
+                F127(0x30a9a9a1c3c51c06d3312d941b23592f),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7eab751dc5c77cb23d89b0b3ea6e8f79),
+                F127(0x4f844d583f155694c0a9b186e6df6e36),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2add440b6bd3854d419018232793dffa),
+                F127(0x318ce3846ae3e417d55480f131df6e32),
+            ),
+            YmX: ExtF127(
+                F127(0x6ebaec63d2bff9f60565062d1a0984f4),
+                F127(0x0dd9434624c8a4e777075fe729e79790),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1b17d8255ee8b364bf8f11e2dfa9b062),
+                F127(0x28106880d081e8dc62c2150cf72c6344),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1a8f0e6c977e1f2ef4a4af0ddfec91c1),
+                F127(0x323716728c4e22ec72a7a3a738b9316f),
+            ),
+            YmX: ExtF127(
+                F127(0x081514248911d367c14069065ba4af3b),
+                F127(0x50e77a9b513400e751bd4afaa8b6c337),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x024886e41a5edcfc46c0051b2a822548),
+                F127(0x336a30b01b9c5675a06b0efa41cac17f),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2b204caa48e9098174fb2c10ca097626),
+                F127(0x39c2e9b6b922303b6902c952b9a17b74),
+            ),
+            YmX: ExtF127(
+                F127(0x6d92930264f15f76b9216b9b3c597419),
+                F127(0x0f0744adfe1bd3077b1297d5eeae1427),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x282fa2e533356c1033b57e265be6a89d),
+                F127(0x4f5d8f5e893dcff53a03995c61dc772c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x596f2241d6a685ae4bfc927efc48023f),
+                F127(0x31018e0d106538423cb3e0afec29b8a2),
+            ),
+            YmX: ExtF127(
+                F127(0x1241d8704982e0112fd00fe944575626),
+                F127(0x1b05f49d0f3de2ce970d56664e6781a7),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x416374a76ba88e98a994ffdf63717e66),
+                F127(0x56781dfab5d2aa4b8b082ced53f1579a),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x64669b840d6081f78151defd1865b318),
+                F127(0x43d438410a974b40e436f4bb5f38e14e),
+            ),
+            YmX: ExtF127(
+                F127(0x06347d9e1ae1828e5832ceb3d666be02),
+                F127(0x2cf2cf61cb4b5ae46979471b39e3ea86),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x12e75cb29aca5768b7ab29eada5a6ee4),
+                F127(0x71f9becd6b320e5ae65b1109d30d1ffc),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x31d62d050ca5458fdc8289026647eed9),
+                F127(0x602bf0b9e3ee5491ea2bbf523a54c1e5),
+            ),
+            YmX: ExtF127(
+                F127(0x2b6b1e3271df5f5825aa73622380ad4b),
+                F127(0x05353c24b8c4354bdbc5efd86aa0470d),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x288a1c8f2b4ea5f7a3c7db3cf5e06bca),
+                F127(0x59d4c1b436673c7dd6152f5e12ce7ca1),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x66d3980f240ad4401e02554e521fcb95),
+                F127(0x7fea351ca94c2f62abf16f6b39a4d9d1),
+            ),
+            YmX: ExtF127(
+                F127(0x0fc6b44f2e7895ea3d62b6f3389163ba),
+                F127(0x2e4099090e603193d5c64403cda7c669),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x46295c9d8e12b6399b5c0faf15fa4c2f),
+                F127(0x5fa7bd736c4c58795ce4add63a5b331b),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x28004c1c2232573947b3471447d1aef2),
+                F127(0x2ab19c1812cd27e8d588437d9a3c5299),
+            ),
+            YmX: ExtF127(
+                F127(0x1ad163800b422b363ae700f680037802),
+                F127(0x44bcdeff21dcbd1d45b7ef36fabc2139),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2c35ee79f7c4cc1441c6da2171e11c7b),
+                F127(0x6492d26f10be050a4852942759c13849),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6a2db2b6dd62181ba6f54e988c50f0d9),
+                F127(0x57526bdb3ba53d20f7d9806b2a5e57a3),
+            ),
+            YmX: ExtF127(
+                F127(0x05d841b042f8f34517ce6cb1f500e650),
+                F127(0x04f4b559abe2cb8eaa800a6c698de970),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x213839bdf94db935c050dfd7259ce49d),
+                F127(0x7d323b8b19f9705ab371258655306204),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x79717069aa89595b26d4502b16b6c618),
+                F127(0x13d601d86c76e1d0f867c0e36db41872),
+            ),
+            YmX: ExtF127(
+                F127(0x185472f3e42e80752dfc8b0d331b7383),
+                F127(0x519a387490f79b9505bd13e72b10eba0),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x045da45d2cf0f7338d09c1b2d3ad2500),
+                F127(0x728d57f59bfe1b09640181956862426c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4fc4831e61dc4e10f9a99f878da2c585),
+                F127(0x0484566b67e9e8ae6dc602cc54394fe0),
+            ),
+            YmX: ExtF127(
+                F127(0x71c0c23a58f3e2bbc5fcf0474a93809b),
+                F127(0x614c2f3eaee4c0a7b400fabe36fe6c43),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1ce8197c88885dcc7610a980d0e1c6c1),
+                F127(0x471ad07baf2f341eeade1c9f3ac2cb2b),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2a8e64281f59cb59d67a837c6b01121b),
+                F127(0x19e0a27dece5058052e701e42f3262ca),
+            ),
+            YmX: ExtF127(
+                F127(0x43484c311b9df1f2b5691c17a7bda6ac),
+                F127(0x43a2c5dda225fae5a68155549bae49ea),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x58911f5623918856fa5e992aed700eef),
+                F127(0x66e6e30cbdd0c3bd648b81a1e48c4da9),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x20f7a86230447685f3ba209c169d266b),
+                F127(0x366c29843d1111f1d1bb5aaa1a0c3d2e),
+            ),
+            YmX: ExtF127(
+                F127(0x27484a64e109e3fb06c78b642dcc9013),
+                F127(0x0b6cb31b1dc24cc18f8eacbca4677464),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2dd426744920f2a2df69c84f898f0fa0),
+                F127(0x489ade7f6a98d8d6c0912a197d4c5c69),
+            ),
+        },
+    ],
+    [
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x124f4123fc05ac97458769f47f203e28),
+                F127(0x330954fed4f00ff83bb936f4ad6d7d67),
+            ),
+            YmX: ExtF127(
+                F127(0x7bf94762d4f9debdc2ce650046f90eaf),
+                F127(0x3c7a6062b4113d962e93172a586dfb83),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x08e3596fc68390345ddb0397147f0d93),
+                F127(0x19021c2119888232374e67ff67639bfa),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x05b4c6e079e1baa3002f5d04fdd55efa),
+                F127(0x1c42f7826a58a77de5678ea3ad74c84c),
+            ),
+            YmX: ExtF127(
+                F127(0x237668d3ede4261ce054668bd2cafacd),
+                F127(0x31ec8c5931cf0ef4edf46a6374aebb32),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x27d8b0ea68259603955c2e95c35b5825),
+                F127(0x6b6cc5c07152bd13b7a8976e427d1ec0),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x001cae9a8cfed89703d88f0ca0b244cd),
+                F127(0x676c9acb7abdec96a844b3a1f693a7fd),
+            ),
+            YmX: ExtF127(
+                F127(0x29f289dc0cddd9b8631b6bd5e0cdbd33),
+                F127(0x1eb2ce650e3eb0590947d57536fb2eff),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4165edfb39f4ae8d2139b3a40e8bf405),
+                F127(0x2e3cc0328c9084f6e061eda67a70d6a6),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6d4d01ce49e8b3d51ef8329ed056063f),
+                F127(0x6dad1c4e170829e00110c92f1656d34b),
+            ),
+            YmX: ExtF127(
+                F127(0x597e5f0ad525e935584c56c590b477be),
+                F127(0x3f586754999c829e6008264d8eb7d36d),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x41754f7d9a3f43643d7ea89df5546a1d),
+                F127(0x1ab27795982628723b0796822ef879a7),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x256ec818ec35a097dc37c9f0bbef7923),
+                F127(0x51df6c61edcad45c4a72da5c09dd5846),
+            ),
+            YmX: ExtF127(
+                F127(0x0ba6bb959ae689f1aef24fcdcf5ce819),
+                F127(0x71ffd591a28a8e4ae667bd65a57b3a9e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6667f2986b2dcf1306c325fa53a7fadf),
+                F127(0x517a104240b8c74a3ef751a6d52a09e4),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x59237cc71b8147f1d08cddfd8c8183f5),
+                F127(0x538acc592d10ef67fff94fd188395933),
+            ),
+            YmX: ExtF127(
+                F127(0x69d42b8114c5fe65ac51ce386ff0eb1d),
+                F127(0x5dc6d98fdf05a341a17eda3995bfe8b9),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x31b58521ecc483caf2304d375ce8be78),
+                F127(0x3dc18b2be3ed95c904d2d8140780222a),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4ffd54a6bc0f38d0a48e1639f2d70d2b),
+                F127(0x482eb41f9178fa9d8ae3c65ba6b7143b),
+            ),
+            YmX: ExtF127(
+                F127(0x6d8532420059eb40240b8b4e87ad4f1d),
+                F127(0x6261076a0daae349c135f77e44275132),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x246165ba3a8bfd9235316bdb3842765c),
+                F127(0x045a2f991647e3b61c2d774bd5177a75),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x0514fada5acd4db5ed3b5923594671a8),
+                F127(0x7cd2badcf2952a91e8297fc358a0f50f),
+            ),
+            YmX: ExtF127(
+                F127(0x26a0d43c1e14c9790da45130ea9ac266),
+                F127(0x360357aff7f67ccbbb62b729fe93a390),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x570daffd86fa470b3ad4835d1c7c59e8),
+                F127(0x17e4bdec2ad76ffcd7c4be698fa3bd96),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x58ba7ae0d64a518e43ce4ea9ead7dc51),
+                F127(0x03abc953ce2630b8e014cc7e64680555),
+            ),
+            YmX: ExtF127(
+                F127(0x2b258fa2e84da952a318620c7799be57),
+                F127(0x17371dd79a3aa556dd88fdc5063b2ffd),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x554552101d90ab2d927b837578981299),
+                F127(0x59109b65ffdb6235b45306218ce54bd0),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x41467fe41c6604f48663e0c4a180a515),
+                F127(0x19d3cb02c6c07517ae2c1aa4dcb73878),
+            ),
+            YmX: ExtF127(
+                F127(0x70dac71a31cac43caa147c97ea6745f1),
+                F127(0x67f228e9f60e7b25b9213ec26af87dfa),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x36687792a4256fa3bfb59b8cf78df3df),
+                F127(0x786a9e1b644b1c90e1be5c1f23177544),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x62ae5bb4b8aaeb594172f47393ca7f5b),
+                F127(0x1fbe20b2edc9cc6dbcd9c431fa631b6f),
+            ),
+            YmX: ExtF127(
+                F127(0x241dd315adc5dd595fdd829fbc0ee085),
+                F127(0x595a82fee5bed2d4b4b688d625f7dbb6),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2b9e85fefc402f7669653ae0cc11880d),
+                F127(0x05d20c575fb34731bb2495b507770a81),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x27012a9665f3febb9d9e623436485ab2),
+                F127(0x44a5860cc0eabfbe586cfef484c04ff7),
+            ),
+            YmX: ExtF127(
+                F127(0x05abeabaaf3220fe6fbfe6e2f3532e80),
+                F127(0x2aa62112b7eafed21bed21f2cb809678),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1ec8fbbcef9158f8e298837cf610190b),
+                F127(0x6a3b842a068b0ef31efe9b3aa4f96f6b),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x605175bbf3fd1c9792dd4b7cd7f827f7),
+                F127(0x3a3ab2e9978db310139bb6419c1f6d98),
+            ),
+            YmX: ExtF127(
+                F127(0x34c6c76025b2bce0c5c95941c9d5dd0b),
+                F127(0x7622cbeb11daf6190d44115a49bb8126),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7191647d355cb45d785bff93164ef5ad),
+                F127(0x581b448b0e9aae3e117f255c4cce6e5c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x790180c539bc468554a4f3cb36225414),
+                F127(0x43cccf5b3a2c010b47064043b7c6b96f),
+            ),
+            YmX: ExtF127(
+                F127(0x1c368f31955725741dfbf3afc14c3731),
+                F127(0x0332d8dd63b37f6000bc2ed3b5070b5a),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2d258e628dacb9ce0744b1908c9bd8f0),
+                F127(0x0bca12295a34e996bba5b4bdb9c61e14),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1a3bed438790be78059c84c66f2175d4),
+                F127(0x304777e63b3c33e4df394f577dabb5b0),
+            ),
+            YmX: ExtF127(
+                F127(0x72e421d1e88e77a459a29d4fe82c5a6a),
+                F127(0x2da03aad8cf2bbb869e6230313312959),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x343099e7a40243a62858d8608fecb0b6),
+                F127(0x3d2028a4f6f15886ba29b675d29a8f63),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x14999b5d6c770e20f068e2d286047d0a),
+                F127(0x78aeb552c15a1cd9d1874a592385da79),
+            ),
+            YmX: ExtF127(
+                F127(0x7b18a19fb54b5745482dcccc23e9c06e),
+                F127(0x2f2c2ce0d1871c13036c896efe9a7a06),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x0649c7e50819d0773b2d9b9ed65492c7),
+                F127(0x49b15b40c4aaf03fcdab66ea7b65e3cb),
+            ),
+        },
+    ],
+]);
+
+/// Tables of odd multiples of `FOURQ_GENERATOR` and its images under
+/// \\(\phi\\), \\(\psi\\), and \\(\psi\phi\\), for variable-time
+/// double-base scalar multiplication.
+pub(crate) static FOURQ_BASEPOINT_NAF_TABLES: [NafLookupTable6; 4] = [
+    NafLookupTable6([
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x287460bf1d502b5fe18a34f3a703e631),
+                F127(0x0c3ba0378b86acdee02e62f7e4f90353),
+            ),
+            YmX: ExtF127(
+                F127(0x740b7c7824f0c55590bf0f98b0937edc),
+                F127(0x4ffcf5b93a9557a5b321239123a01366),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5948d137556c97c6297afccbabda42bb),
+                F127(0x0caf2b720a341f27a8189a393330684c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5742f77c98a526ba892756b15bcf68c4),
+                F127(0x14ef680aee75d0f7340a5a1de9f89f9b),
+            ),
+            YmX: ExtF127(
+                F127(0x0212c41116c33c9584e770e14043a41f),
+                F127(0x5949df08518d5d2835b791e6de4dc0e2),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5a5183ce844391d36a0e120744ed10db),
+                F127(0x2ce2037e470e20886f618b158afdba50),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5f9876d5196704511f49fa149a64ba3c),
+                F127(0x020f1a557d8fd726030105056f55586b),
+            ),
+            YmX: ExtF127(
+                F127(0x694fbcbe7fe58390df4cb175b06d86c8),
+                F127(0x09dbe9924b58f8ec7933294a756a1b67),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1c07969fc87a0ba7590f4403cdf197b6),
+                F127(0x5508976022f1b096c496477712252367),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7a0a0cccacc838fbefda361e452e1775),
+                F127(0x24d9b6b418cbcb93b07e791c0be5dc5f),
+            ),
+            YmX: ExtF127(
+                F127(0x3986a158cb96d595497970f3c6117e03),
+                F127(0x305cafda7e4df9d68f80586ce692612b),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7ef989c0eb583079c1a1c2e06452914a),
+                F127(0x4fee236d58299c6b3a765b1f7364b099),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x53bbd86b7396bc096f81095f770e8419),
+                F127(0x625dda1d2901c78b2b72ba726b2b4210),
+            ),
+            YmX: ExtF127(
+                F127(0x0556598c7358d3320ff5bc7b18cd2b3e),
+                F127(0x0e7f58e5e919a97e0991245f20ff50d7),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6447bc93f87c198a5a0561373b758756),
+                F127(0x6b214425475c1bfaf9230604c34c7520),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2129459d86f4493ce93de62d6a7f9497),
+                F127(0x612434fec3f4a1b3456394c7c464cfe4),
+            ),
+            YmX: ExtF127(
+                F127(0x0c6d3854f9e0a3ff1ed91eddf44261f3),
+                F127(0x24691fbdca16910cd3fd153188a7e4e3),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2aa61cd373f759f4be97465cd7625c9d),
+                F127(0x1a0ae39e50da20ba824d5763a326d62b),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6c3687109cdd18c632d0c8481ee4c3b9),
+                F127(0x67bfa41fb52ce9c6e52717142fbf95da),
+            ),
+            YmX: ExtF127(
+                F127(0x49a6ca0ae3fb66264e24d6a088a01474),
+                F127(0x674888f5aa6d3062d67f8faa9103191e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x406b2fd18d35b3144ba73824c2e85a99),
+                F127(0x11d2f222317b160ea7087b1bea728ac1),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x22a196fabbce31a2f8946e007e23a469),
+                F127(0x240fe9953827a3245309ee1bdc1216ba),
+            ),
+            YmX: ExtF127(
+                F127(0x603b8149ed16b1b0f9fcb89b63aeb5c7),
+                F127(0x4a5e32af612f948bb1f1876c02cf61fb),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1ad9379136e53aa5fc491aede69a8813),
+                F127(0x2f4014f7fe2c12ca5da50db1d5e6c123),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4c218521c3745a9be4f6791d7685c3f5),
+                F127(0x1462a12953cada7b0c0521af98555f97),
+            ),
+            YmX: ExtF127(
+                F127(0x5783c531ec98bb870bb2ab63d6452c1b),
+                F127(0x49f982b930e86719737def53605dbc9c),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x45ad6574cdbae99e75b16790cb5211e3),
+                F127(0x45029a09cc468c881062b72dfeec9851),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x17bd291eaa9ad0ea532240de77f3a1f2),
+                F127(0x3a7412052021778ee0a2d7efc2f8a0a0),
+            ),
+            YmX: ExtF127(
+                F127(0x7fd603b689a7b1f3b0dfb0976acc90df),
+                F127(0x6340743b631849a31152579ccb00d6c6),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x143265a6d53fef0bebaa47290e0cda01),
+                F127(0x0e9780cc39586f2a45325d6fd981e75a),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x50d230b51893e841a4f68d207a8628dd),
+                F127(0x55975c063969292ef3bd769a4bb504b6),
+            ),
+            YmX: ExtF127(
+                F127(0x07ff86cf8ed731fd07727ba25fb8756f),
+                F127(0x70753a70874218fcef57fa40cc35a1f0),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5aa9d68f1a59df86615954e2342b973c),
+                F127(0x2e749114d60a3d233b8e9e9ff5e44468),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x55f91a63d69aae6d14a1b91ec176db4b),
+                F127(0x2acf1f475facaafdf42382327b1b6d27),
+            ),
+            YmX: ExtF127(
+                F127(0x3baaf4e5c4a45f77fd9069b479b58968),
+                F127(0x5466cb5018f50981a2ac9ab98a7aaab6),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x31ea90cdea1bbbe43e6ba27771ba3205),
+                F127(0x464cb0415a510d7d0000416b5c557393),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2b9c8ecd7fabe736d02087d206ff2bbf),
+                F127(0x046ea0b7767700a7b2b56d3842caab0d),
+            ),
+            YmX: ExtF127(
+                F127(0x5992a354bef7d0ca113a7a889e317310),
+                F127(0x052661f7678391543edda94ed50388bd),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1d19c2f2d2f644e54c28edf6e19e28e0),
+                F127(0x680c4714b83580f55d732148db35ab3d),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x789e609bc77ae11ca374f282bb80ccec),
+                F127(0x1c548b5b857721b110d2577d599b45f2),
+            ),
+            YmX: ExtF127(
+                F127(0x3c1562912d1b4ed27baea726b4543fdf),
+                F127(0x1414e523d3c7a900d6362203b7e82082),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4da4265e3ce80fb47ca349951c1d23a9),
+                F127(0x4ebac9e5b5bf980b7981ebbcaca9ef36),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x3f54acfc25c6340fabd2c1dcf49cb5a4),
+                F127(0x67216b7cb3695e8c202eeffabbd11cbd),
+            ),
+            YmX: ExtF127(
+                F127(0x2eebebdff7fa7afbff7cbcf9b23fc9f1),
+                F127(0x1b8fd98df522902c71156befa111f85e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6cf0ea960e01d8ed6b28ebad62519791),
+                F127(0x323da065cb3df0adb4617bc2006967d5),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x02db8f2b509a7cc231687d0741e24d9c),
+                F127(0x68c360f01d6e6d2b9243f85924320527),
+            ),
+            YmX: ExtF127(
+                F127(0x6f56ccfc85c5f3a92351c5e877d5306a),
+                F127(0x0b3337554c83f9711b09652837c4928f),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x46829694ba08c64fe2931be2ccc783ec),
+                F127(0x1474b333b000d1709f35e36358e2c6ac),
+            ),
+        },
+    ]),
+    NafLookupTable6([
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7791feea9015f17044955b062a6ecded),
+                F127(0x2632adbca5388026736bf603d12fc35a),
+            ),
+            YmX: ExtF127(
+                F127(0x4ee9adfe8600e32d956e4c48e1697c4f),
+                F127(0x34a3d7f4bf457353a584042a0da56406),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x15321ee855941f4e8d4fd4fe00176fab),
+                F127(0x0c7d7c618aed0ba8670701ef81f340a4),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x34935a39e31bac6573283131d9bfd9d6),
+                F127(0x250dd54e18478ac6466cfbbcaae8b991),
+            ),
+            YmX: ExtF127(
+                F127(0x618ea014fec50e04659e46c51e40de4f),
+                F127(0x572cabbb6688c4f7fe64d883080b877c),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x06cd734876378120a2c817493a834146),
+                F127(0x36942f5191db53c4e3de0b717336a849),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2a9a144b8087fa96a3f9adf66abf4d88),
+                F127(0x1be40a8616928babfe49fefcb78a5b4f),
+            ),
+            YmX: ExtF127(
+                F127(0x1fc66ea68369351007a901975521f7aa),
+                F127(0x1f374495b918c7374dbf0084ba42380e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1346f4766fcdaa07b8346956a380a00a),
+                F127(0x775e7f3274dc1316b4db5689d46312c1),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x144390a33b3e86df07898828f32341c0),
+                F127(0x127652de0022087370bc604ce1e9c5e4),
+            ),
+            YmX: ExtF127(
+                F127(0x236f4585150161f42874bc669df50d45),
+                F127(0x7cc92a61650597453bfa4ffd318214e2),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x26676bd59c4fcc3b2fae0e92090ef72a),
+                F127(0x66455887e98686e7220c030974d1d447),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5517a86f840feb634164b8e4d8760ddc),
+                F127(0x3a7f03ceecc160b9d9b42c6c9371cade),
+            ),
+            YmX: ExtF127(
+                F127(0x1b6290c327842533dd4086d64cae366c),
+                F127(0x16621925ca10d31e144efcd2a7a0e82b),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5a90f97edcb1c54ea9dcd13118e208f1),
+                F127(0x6f061a3569a80b5580c47331c8749d99),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x29106c98122245f40f6abf619e2a15c5),
+                F127(0x4f379a379e15f4105860b10985c9b47f),
+            ),
+            YmX: ExtF127(
+                F127(0x2c475167ad9b283c2dd6f45df68e1678),
+                F127(0x5532bc26a40c536523b7aa00952a6a3a),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x4fa3127a9aefa56fa5c0a8be3596ce22),
+                F127(0x3c7727d45ae87854944e843aa973e67f),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7bca8e04ad3bbb9c48fa2ce675117ea4),
+                F127(0x3337d3a6a03b2286d57439e4726f88e5),
+            ),
+            YmX: ExtF127(
+                F127(0x514bd76734e6c0a1b0b6172902005953),
+                F127(0x0abe13cee7f1b75ef97f8934eed7c6b4),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x634f966d7a6e11df6c88107a120e54a7),
+                F127(0x68d49fc65522b73a5044c53109b94097),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x542c4c5fd999a22469e295cd8c444666),
+                F127(0x7133fa786a87ecb413ff89418b5da76f),
+            ),
+            YmX: ExtF127(
+                F127(0x52ddada7931c4dcc2f180926456402b4),
+                F127(0x014ec2a2ec2318266eaf0d2130c71590),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x157acbfab118b219ac05b61443b34dd6),
+                F127(0x0abf4a4da29a0eb8e4e2f4b84ad01099),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1bd259c4726869ed5f852b85b59eab1f),
+                F127(0x17a48442bcf58a00ce565d9287790a15),
+            ),
+            YmX: ExtF127(
+                F127(0x2336d07a710da07a01e519522381363b),
+                F127(0x2f7a51474c23b8a9cfebf2fbdc714cb2),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x40e8d8d2d0a0980677db2a07d4e3716c),
+                F127(0x53f9cae0470172fd644363ce6d401ae4),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x15028204f3d6d69658d96ecd8ddadc53),
+                F127(0x738c5371236c3e566f40a09214439ce2),
+            ),
+            YmX: ExtF127(
+                F127(0x4f1899449a810fee64f87ee7a28bf9fc),
+                F127(0x6170cc24283856bcd0aa95f4bf21e376),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x227ea1563fa2e0129dfc4927d764ff75),
+                F127(0x473d3bea07a5285eaddd3665622ce087),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x78e584c740dd18edc0b986ee0d2b0eb2),
+                F127(0x1c6aed5ab59bedbbd5adbf30a04fd508),
+            ),
+            YmX: ExtF127(
+                F127(0x4a58fb6b3f89631925d05fccbddb5ba1),
+                F127(0x46a445de6d5b07e5db2f6343fd8144fa),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x57b2515923b15c9ff67a06684fe9e1da),
+                F127(0x62f4b9b26f04dab550439940820a2a0c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x009bc6176f10fffbe79ea601d01b033d),
+                F127(0x253d0a9e626dd400333bff2f907ed39a),
+            ),
+            YmX: ExtF127(
+                F127(0x2d1b6a7a5b39342d7a9bbedcfcbef06a),
+                F127(0x2e8cde9d82c15cb0badfb462a124cc9a),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x04cb0b8fa40752947c3f81bcd6f1b2a1),
+                F127(0x59fef93442883553fa36d3db38cbd304),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7b9d63ac17b0198291982a741cb9342e),
+                F127(0x611069ad9fa0f0a4530b4ec25a293ece),
+            ),
+            YmX: ExtF127(
+                F127(0x6fe6f8f4d6d015b07a262a59b656a79d),
+                F127(0x24b0c507058c911c2c2fd7641a5d4e50),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x68d0b01b13432761834882e492fe45ae),
+                F127(0x123e3a93006d7d010eacaaaf94178b8c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4fc960ab4408584becf2fe69377ff33c),
+                F127(0x4989681cd1d09a932adc445b1ee45654),
+            ),
+            YmX: ExtF127(
+                F127(0x7f6ffbbeee861c1579509599afe9e3b6),
+                F127(0x5e8bd52289b6ad272ed2859fd6391b25),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x510999e865f0cd54c949280adbce7c79),
+                F127(0x4b2c0ea4bab08ef27f957314ce7d373b),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4609a0ea235076972d7cc08b5c05a8db),
+                F127(0x5e4d5903fdef61e6e204ba35182c55b8),
+            ),
+            YmX: ExtF127(
+                F127(0x782a3fd3ab62a179fe63842f2826598b),
+                F127(0x0fb4c6bdd637fba2d2f01a1979e5a0f3),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x14859008c3d223c0fbff4c192020c350),
+                F127(0x1d78daf483fa12cb65ed7a889c1a2e55),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x54fde757373065155b54d11b01bc09ca),
+                F127(0x712d1f394adcda9989725231105b63a7),
+            ),
+            YmX: ExtF127(
+                F127(0x04dd8f7bbd4c5381b554006ee9abefab),
+                F127(0x637a53de6b57122f98d22b3a31995549),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x236f2a9514250df68367d69b4c92da63),
+                F127(0x08522e36bc4b65f8b265509af63d7b7c),
+            ),
+        },
+    ]),
+    NafLookupTable6([
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x3204eb91cfe9ed6c6f006249351471f7),
+                F127(0x6d70ed88d5de535be09af1c83c13afa2),
+            ),
+            YmX: ExtF127(
+                F127(0x5c73bedb8d96f3da2078873d1a2faa1f),
+                F127(0x7a40ec2fb54eea8541bbb407a3a1ce1d),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x10acf67805927b6ad6d569cb9dd722e3),
+                F127(0x57b175c9f59904e227c61d818cc0ea05),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x51431f647b46b89a4f7b40bc92b5a60d),
+                F127(0x6b36059700809a1ccd84dd55cc2a720e),
+            ),
+            YmX: ExtF127(
+                F127(0x630c0c1a146c77d478e3e5dd060e9a0f),
+                F127(0x4728f0604b16a06dc9925b0dea8fee2b),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x2484f7281864709bb4601050635b2318),
+                F127(0x6425d4ff23dd3a5bbe2ed2a2523211db),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x2733d1e1adc6d5eef0868c09017aef5e),
+                F127(0x36d753ced54d5727a631db49f17f87e9),
+            ),
+            YmX: ExtF127(
+                F127(0x1dcc4d611dd55b04451d17fb6c4af537),
+                F127(0x2fb2ca1271592c3d0bb8de0c8d3e549b),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x190809a196504d10d877914ffbc31ced),
+                F127(0x13195c678b4b01fa44bdd65a970277e3),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x61c7c870565e4508e69a41a54f84d41f),
+                F127(0x7f065480e257152aeca2d2fc6f0e1c9b),
+            ),
+            YmX: ExtF127(
+                F127(0x43fcdb8db58a324afaaa9f7c3a8873b0),
+                F127(0x4eab135af328b9d9969a79026e9da7a2),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x69eba4fe1a6b6f32b38aaafe87f85f7c),
+                F127(0x273072bea774f9e75607f6c6b4d27cbc),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x161f8cd433c28bfa3c1149e3c8d51db0),
+                F127(0x442b5d405f2036bb765a61f218fe70da),
+            ),
+            YmX: ExtF127(
+                F127(0x3d5dbb33505cc95696f790271c564cc1),
+                F127(0x2da978b45bb70ce6621a38b446af395c),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x46f2e33e55e86df8755aca711da49388),
+                F127(0x67df47d68d8f6d12fc5b454d5cb7be24),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x0400219c89c2d13e7a1e224893898aad),
+                F127(0x4df64d5df8b60ad26c969e4d63d460d9),
+            ),
+            YmX: ExtF127(
+                F127(0x290c4b59e684b4ef1feed05a45ff89ed),
+                F127(0x4ac6037e76561c9697ffbc3df096adb6),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7169e0a1d96aa1be1bc40299115e51b1),
+                F127(0x1cc6a0603081a17843f55f8b6bac596c),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6ffb86eed51d29318e1d2db69bc925d0),
+                F127(0x338198152fcd6d7c3ad1eb242e0af1b5),
+            ),
+            YmX: ExtF127(
+                F127(0x05d9242fe1c60b02c1f381496df13943),
+                F127(0x24d8ba5ac76b12b839617510de7eec81),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6c51317b3a8a93f0280eb2db9e548483),
+                F127(0x2da9de86c39f9aa6b2a9f90939bd1235),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7be2be5ad32761697f54917103127b97),
+                F127(0x0500df3bbb1f8a4ec969d703d31e9da7),
+            ),
+            YmX: ExtF127(
+                F127(0x049575a992d09345ea05c77685795917),
+                F127(0x383fad35a8e035cbd567f8de2daabe35),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x52b3953221860c5ab9353eb2bbd43d56),
+                F127(0x4b0db0b4a7b3279cf9e4bcd46dbec03e),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1bd2ce464b5522158cc5f6b6e1ff80c0),
+                F127(0x3b4ce5bb2f42a9fcd008eb25b39c4236),
+            ),
+            YmX: ExtF127(
+                F127(0x3e022cb14bc4c5b9e1f249681d153d9d),
+                F127(0x560d3fb258bec4958a11d021c8ed5a53),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5c8bccd2b1b3efd3f4405852705a6012),
+                F127(0x337798cb3e93dbbad93c0f63ba7ce0c3),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x579afe689f3ebcce7a9f68cf800c8e88),
+                F127(0x3802410c4e1b274e7dd41d6cdfbdb4a9),
+            ),
+            YmX: ExtF127(
+                F127(0x2f7c8133c74bde2364241d770cf0db02),
+                F127(0x741b1d88a3cee37bf3c3fd835ed1952e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x0c80dd9e0f7a91e174e1ae644683c68f),
+                F127(0x4b3eb97b6a39d2523984d741f3e47c24),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x11d09fdc04ec3b4132e9b9410da9a195),
+                F127(0x296e095589e0ce05f92fd5e53cddea30),
+            ),
+            YmX: ExtF127(
+                F127(0x7e33fbba44ecb32c4e3200c3a283b696),
+                F127(0x5c8ebb260b5ec084ed3c039790ad0033),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x12fbec9d4f5bb155a667455bb79d2e9d),
+                F127(0x0ca652ed7065d80b3aa5f6bb4d0d8d49),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x41644ac1a602f9f2b7938753d51c6f83),
+                F127(0x71057b4b8b93128284223d4d63c38f7d),
+            ),
+            YmX: ExtF127(
+                F127(0x7536c8a19c33c201d39fa015165f47b5),
+                F127(0x456c98c2b4198511be713ca4166c2dad),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x1d002f1cfe1a1ba74793f25e1cb44658),
+                F127(0x095dece028426bdb9f9ed6e1e1a27957),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x481c63a0d9b25e99e57d3412fc1001d6),
+                F127(0x24af047d79ed4683c756b6ba0dc02aa5),
+            ),
+            YmX: ExtF127(
+                F127(0x418b45e570802012e37ac10133b68275),
+                F127(0x7c5661923b8c974087578def0c3900ce),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x0ac6100825e4eb3c5f4ab0a6fdda7366),
+                F127(0x436e5979933ddde8308528e42c9e4d32),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x63d1768a46f33dc70cd6ebe123352222),
+                F127(0x474438da7140411d96cc55dff38c9273),
+            ),
+            YmX: ExtF127(
+                F127(0x6bf820a3aa675050a184b89b81cf6402),
+                F127(0x3f2b8f859a8e0cba3bd4720417391f0e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x07eb1ac74165097ded952561b125da29),
+                F127(0x5ab896a489294a6cc3f70d0c7db0a9fd),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6243b039f25d0456d4b608975c20018d),
+                F127(0x20035c09d2291e42f766e98fc24c7464),
+            ),
+            YmX: ExtF127(
+                F127(0x24bcba5505f90657cc0e5b5eeb462524),
+                F127(0x3b621ec4188264d443a98d98e4fa9bf6),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x31a20844a3316d23633472fe235c812c),
+                F127(0x22d482f5663780f947b80db7d7f5d0bd),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x25076d0624bf137e4df227dc52142020),
+                F127(0x0ce469dbb5ada433cb4a6ee30a657645),
+            ),
+            YmX: ExtF127(
+                F127(0x44f82274a8e8f538fdb06251f65b9c5b),
+                F127(0x0ccd61d1abb61d0d98fa4c81cdec4b97),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x35dcd9ccf8e5f919b9dc371344c5ab54),
+                F127(0x121b5aa1af6024da67fc81f369ba5722),
+            ),
+        },
+    ]),
+    NafLookupTable6([
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x56381ebd8181b50e19e04d1b2b0fe7fb),
+                F127(0x08acaece8ede76855c8970c249df4ac3),
+            ),
+            YmX: ExtF127(
+                F127(0x623edc8d92e4ac3ac44f1a71aca0d20b),
+                F127(0x20a9ba37315b116e5496a7e5885a0c95),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x23c44c42ebef2ff53765873809f5b55d),
+                F127(0x3217815b72b8a9eb56a96d921f724573),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x31f0b36e85b8c70b2cc1b42f5350a489),
+                F127(0x1af8ea26b3786eac504a5c8c4d2ce34d),
+            ),
+            YmX: ExtF127(
+                F127(0x21e399d04247bf9a69bc5e26d7afd62f),
+                F127(0x476212b9fe9a6fd46e6d6676a88efb27),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x5f7570be65e694080740fb65284168de),
+                F127(0x6565489007c4ed6d0166c3279dd81c29),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x00251709f2e210f7bafb5bd37b5219c9),
+                F127(0x0f3c0df3be3de8110d22639b51c1198b),
+            ),
+            YmX: ExtF127(
+                F127(0x0834744318ffa0aa3552612be3374eef),
+                F127(0x20c359f5de8b6614cb9f1c1e3557a00c),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x42165771b46b75d7d319482a34d05268),
+                F127(0x4d072f70067a47e1ca336c22e8d911a6),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4c8c7eaf7cc2d6979022c6f101555e9e),
+                F127(0x25110bc01b06c9c1629810b2d8044817),
+            ),
+            YmX: ExtF127(
+                F127(0x6cc36f151f52b4e81bf9c06bf39eaff7),
+                F127(0x47dcb0dc89db382176b73a6a14b62068),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x625b5c93b973c417fe9dfeac2f670f41),
+                F127(0x6bd35f3e0992bb2b5f8c917930133c1a),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x7981d8fd1636276703b5391a85409e5e),
+                F127(0x67356a7ef48b2dc3db45c80a32a23cb6),
+            ),
+            YmX: ExtF127(
+                F127(0x07a1e954e5032bd66189236e9f01adaf),
+                F127(0x25d67e4163cec01453d627199c69727e),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x3112be4cb5dcbc7418e7bb6a63a80738),
+                F127(0x116112cbeabb734dad9ad6d381643f04),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x4d780300822436de32623abe2d66ff07),
+                F127(0x40db29b39ce867009bed066c04497808),
+            ),
+            YmX: ExtF127(
+                F127(0x52f227f2b1b9b40d6e5e5eb3805602a5),
+                F127(0x6d8bca423ee270bc51c2c4c197a18394),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7dd66c3970f940c6d6e60cfe8fb07f72),
+                F127(0x75fcf8b00160d72966aea7b59a0b17cc),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x0dc3600425feedd5bedc5ea39b2402b5),
+                F127(0x205ee93e3aae976aadc1ddf2cb1b6631),
+            ),
+            YmX: ExtF127(
+                F127(0x7d12eb776d56872c7a2cb4e333c98498),
+                F127(0x4600f0a53fac94278e339bc1b41599fe),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7b54e020b22db7421049d3a372f14304),
+                F127(0x27a1178b1115f0c4d567962272a35739),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5cb96fd1a9d9d4866cfb39d619c35e1b),
+                F127(0x4a73d7b2ba9321d1af45cef7fb4fffea),
+            ),
+            YmX: ExtF127(
+                F127(0x2769b50579e8f73444b46b4a80be86ac),
+                F127(0x2bccfba1cbe995b6ab5d109e7472f372),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7acb287da1561c53c00026115332f6a3),
+                F127(0x7731d1b2878dae1321555c608cd90dd9),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x592b5fa180ec846732122bf5ec1a0649),
+                F127(0x484c1cc5bb34819d876be1b5ad9ce66f),
+            ),
+            YmX: ExtF127(
+                F127(0x2766065f0e4d22ce08e4cc425b30b06c),
+                F127(0x3a835fcc7fc456a6d90825644987aeff),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x41d767ecca55f839f4d801d2cc806d69),
+                F127(0x74d01b97462211cbf2dea9fd01f1e74f),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x5cdf66a69029b231e43e280ad29f80cc),
+                F127(0x388e38b58d0e8c79e8d655a03c862cd9),
+            ),
+            YmX: ExtF127(
+                F127(0x14d6fbee4d6cbe745d9aaa4848ff83a2),
+                F127(0x1bb7b9cd75d4b5410426dcda912109ea),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x35a3c5882b31367a3a3c0504b39b8505),
+                F127(0x66abca7e20202034678793d635a6473a),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x18f29036544d26844a90ff1dad300021),
+                F127(0x36490f5645d18cc82036d39b8f69095d),
+            ),
+            YmX: ExtF127(
+                F127(0x7f8108a04558487e9414d7368ad3562e),
+                F127(0x03f413ea960537bb93db0e56d653e40b),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x6c5d9da4a5ee7305984717b77f7267ef),
+                F127(0x274397f8e79a239e725318dc36060a49),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6292b2505c7866e3bda7965b4095bab0),
+                F127(0x37c560f40242a859451fb6a0672d6733),
+            ),
+            YmX: ExtF127(
+                F127(0x63451986f0c22ee1151e56eb818f1423),
+                F127(0x178cdc734a32b96a9275ff873a5c75e1),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x76518aa0dfd96ddcff7adbb24244aacc),
+                F127(0x0584d44c10a3e6dc161c1c8c81071219),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x1298e49c34514ebd2727282a09e9acab),
+                F127(0x6072c8b87dd26bc60323d059ca1c0e6d),
+            ),
+            YmX: ExtF127(
+                F127(0x2a977cb5aae4ea2a36eca2ab28d36f26),
+                F127(0x04d60af0ed661d29f157d43a0b9546a7),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x7677ef9a2158917134bc1080126e4402),
+                F127(0x32c0daf0b57f20acbd13797278f07a40),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x6cd07286c4e670ecbc83fd1b8366dc2e),
+                F127(0x6e7e9285f2247e8bf35485a3f339dc8a),
+            ),
+            YmX: ExtF127(
+                F127(0x43fa5197eed852a6a9d19d3a09943bae),
+                F127(0x4a100dcb1312cbe9f911398a043242fe),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x614fd829368d7937be2fd86be910a692),
+                F127(0x46f1d23e1b0dca7edb5a98b1a92d578f),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x68bc89078129ce918bf4c6725e813f36),
+                F127(0x2b6e0f4e42178ce5ff56503ae28f5c7f),
+            ),
+            YmX: ExtF127(
+                F127(0x7aa90b66280ff6c9a97cd947ec65895b),
+                F127(0x6a748d0ac02bb713ebbaf32df158a0a0),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x16934947f6485b69df79b5d619e83397),
+                F127(0x20791e276a7460c9e75185521ab32881),
+            ),
+        },
+        CachedPoint {
+            YpX: ExtF127(
+                F127(0x0bf079518e66e1d3d25c403e22c70bc9),
+                F127(0x66bd2c6a30be232c45dd5c971d3711de),
+            ),
+            YmX: ExtF127(
+                F127(0x30ed414e71dc08a2607829e5b29e53ca),
+                F127(0x5a881a121f37fc5c3fd38589ea0f1d39),
+            ),
+            Z2: ExtF127(F127(2), F127(0)),
+            T2d: ExtF127(
+                F127(0x321fe45e13afae2d27b9394368987a4f),
+                F127(0x02166d52f45eebbdc6feb75080f33ea0),
+            ),
+        },
+    ]),
+];
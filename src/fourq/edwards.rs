@@ -0,0 +1,533 @@
+//! Points on FourQ in extended twisted Edwards coordinates.
+//!
+//! A point \\((x, y)\\) is represented by \\((X : Y : Z : T)\\) with
+//! $$
+//! x = X/Z, \quad y = Y/Z, \quad xy = T/Z,
+//! $$
+//! as in Hisil, Wong, Carter, and Dawson, _Twisted Edwards Curves
+//! Revisited_.  With \\(a = -1\\), addition costs \\(9\mathbf M\\)
+//! using the precomputed constant \\(2d\\), and doubling costs
+//! \\(4\mathbf M + 4\mathbf S\\).
+//!
+//! Points are encoded in 32 bytes as in FourQlib: the encoding of the
+//! affine \\(y \in \mathbb F\_{p^2}\\), whose top bit is always
+//! clear, with that bit replaced by a sign bit for \\(x = x\_0 + x\_1
+//! i\\).  The sign is bit 126 of \\(x\_0\\), or of \\(x\_1\\) if
+//! \\(x\_0 = 0\\); exactly one of \\(\pm x\\) has it set, unless
+//! \\(x = 0\\).
+
+#![allow(non_snake_case)]
+
+use core::ops::{Add, Neg, Sub};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::fourq::constants::{EDWARDS_D, EDWARDS_D2};
+use crate::serial::ExtF127;
+
+/// The 32-byte encoding of a point on FourQ.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CompressedPoint(pub [u8; 32]);
+
+impl CompressedPoint {
+    /// View this encoding as an array of bytes.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Decode a point, returning `None` if the encoding of \\(y\\) is
+    /// not canonical, if there is no point with that \\(y\\), or if
+    /// the sign bit is set for \\(x = 0\\).
+    ///
+    /// The work done does not depend on whether decoding succeeds.
+    pub fn decompress(&self) -> CtOption<EdwardsPoint> {
+        let sign = Choice::from(self.0[31] >> 7);
+        let mut bytes = self.0;
+        bytes[31] &= 0x7f;
+
+        let y = ExtF127::from_bytes(&bytes);
+        let is_canonical = y.is_some();
+        let y = y.unwrap_or(ExtF127::zero());
+
+        // x^2 = (y^2 - 1)/(d y^2 + 1).  The denominator is nonzero,
+        // since d is not a square.
+        let yy = y * y;
+        let u = yy - ExtF127::one();
+        let v = EDWARDS_D * yy + ExtF127::one();
+        let (is_square, x) = ExtF127::sqrt_ratio(&u, &v);
+
+        let x_is_zero = x.ct_eq(&ExtF127::zero());
+        let flip = sign_of(&x) ^ sign;
+        let x = ExtF127::conditional_select(&x, &-x, flip);
+
+        CtOption::new(
+            EdwardsPoint {
+                X: x,
+                Y: y,
+                Z: ExtF127::one(),
+                T: x * y,
+            },
+            is_canonical & is_square & !(x_is_zero & sign),
+        )
+    }
+}
+
+/// The sign bit of a canonically encoded \\(x\\).
+fn sign_of(x: &ExtF127) -> Choice {
+    let (x0, x1) = (x.0.canonical(), x.1.canonical());
+    let x0_is_zero = x0.ct_eq(&0);
+    let bit = u128::conditional_select(&x0, &x1, x0_is_zero) >> 126;
+    Choice::from(bit as u8)
+}
+
+/// A point on FourQ, in extended coordinates.
+#[derive(Copy, Clone, Debug)]
+pub struct EdwardsPoint {
+    pub(crate) X: ExtF127,
+    pub(crate) Y: ExtF127,
+    pub(crate) Z: ExtF127,
+    pub(crate) T: ExtF127,
+}
+
+/// A point prepared for use as the second operand of an addition,
+/// as \\((Y + X, Y - X, 2Z, 2dT)\\).
+///
+/// Precomputed tables store points in this form, saving the
+/// multiplication by \\(2d\\) for each addition.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct CachedPoint {
+    pub(crate) YpX: ExtF127,
+    pub(crate) YmX: ExtF127,
+    pub(crate) Z2: ExtF127,
+    pub(crate) T2d: ExtF127,
+}
+
+impl CachedPoint {
+    /// The identity element.
+    pub(crate) fn identity() -> CachedPoint {
+        CachedPoint {
+            YpX: ExtF127::one(),
+            YmX: ExtF127::one(),
+            Z2: ExtF127::one() + ExtF127::one(),
+            T2d: ExtF127::zero(),
+        }
+    }
+}
+
+impl From<EdwardsPoint> for CachedPoint {
+    fn from(P: EdwardsPoint) -> CachedPoint {
+        CachedPoint {
+            YpX: P.Y + P.X,
+            YmX: P.Y - P.X,
+            Z2: P.Z + P.Z,
+            T2d: P.T * EDWARDS_D2,
+        }
+    }
+}
+
+impl ConditionallySelectable for CachedPoint {
+    fn conditional_select(a: &CachedPoint, b: &CachedPoint, choice: Choice) -> CachedPoint {
+        CachedPoint {
+            YpX: ExtF127::conditional_select(&a.YpX, &b.YpX, choice),
+            YmX: ExtF127::conditional_select(&a.YmX, &b.YmX, choice),
+            Z2: ExtF127::conditional_select(&a.Z2, &b.Z2, choice),
+            T2d: ExtF127::conditional_select(&a.T2d, &b.T2d, choice),
+        }
+    }
+}
+
+impl Neg for &CachedPoint {
+    type Output = CachedPoint;
+    fn neg(self) -> CachedPoint {
+        CachedPoint {
+            YpX: self.YmX,
+            YmX: self.YpX,
+            Z2: self.Z2,
+            T2d: -self.T2d,
+        }
+    }
+}
+
+impl EdwardsPoint {
+    /// The identity element \\((0, 1)\\).
+    pub fn identity() -> EdwardsPoint {
+        EdwardsPoint {
+            X: ExtF127::zero(),
+            Y: ExtF127::one(),
+            Z: ExtF127::one(),
+            T: ExtF127::zero(),
+        }
+    }
+
+    /// Encode this point in 32 bytes.
+    pub fn compress(&self) -> CompressedPoint {
+        let z_inv = self.Z.invert();
+        let x = self.X * z_inv;
+        let y = self.Y * z_inv;
+
+        let mut bytes = y.to_bytes();
+        bytes[31] |= sign_of(&x).unwrap_u8() << 7;
+        CompressedPoint(bytes)
+    }
+
+    /// Compute \\(2P\\).
+    pub fn double(&self) -> EdwardsPoint {
+        let A = self.X * self.X;
+        let B = self.Y * self.Y;
+        let C = self.Z * self.Z;
+        let C = C + C;
+        let XY = self.X + self.Y;
+        let E = XY * XY - A - B;
+        let G = B - A;
+        let F = G - C;
+        let H = -(A + B);
+
+        EdwardsPoint {
+            X: E * F,
+            Y: G * H,
+            Z: F * G,
+            T: E * H,
+        }
+    }
+
+    /// Compute \\([392] P\\), which lies in the subgroup of order
+    /// \\(N\\) for every point \\(P\\).
+    pub fn mul_by_cofactor(&self) -> EdwardsPoint {
+        // 392 = 256 + 128 + 8.
+        let P8 = self.double().double().double();
+        let P128 = P8.double().double().double().double();
+        let P256 = P128.double();
+        P256 + P128 + P8
+    }
+
+    /// Check that the coordinates satisfy the curve equation
+    /// \\(-X^2 Z^2 + Y^2 Z^2 = Z^4 + d X^2 Y^2\\) and \\(XY = ZT\\),
+    /// with \\(Z \neq 0\\).
+    pub fn is_on_curve(&self) -> bool {
+        let XX = self.X * self.X;
+        let YY = self.Y * self.Y;
+        let ZZ = self.Z * self.Z;
+
+        let lhs = (YY - XX) * ZZ;
+        let rhs = ZZ * ZZ + EDWARDS_D * XX * YY;
+
+        let on_curve = lhs.ct_eq(&rhs);
+        let segre = (self.X * self.Y).ct_eq(&(self.Z * self.T));
+        let nonzero = !self.Z.ct_eq(&ExtF127::zero());
+
+        (on_curve & segre & nonzero).into()
+    }
+}
+
+impl ConstantTimeEq for EdwardsPoint {
+    /// Compare the affine points represented by `self` and `other`,
+    /// by cross-multiplying the projective coordinates.
+    fn ct_eq(&self, other: &EdwardsPoint) -> Choice {
+        (self.X * other.Z).ct_eq(&(other.X * self.Z))
+            & (self.Y * other.Z).ct_eq(&(other.Y * self.Z))
+    }
+}
+
+impl ConditionallySelectable for EdwardsPoint {
+    fn conditional_select(a: &EdwardsPoint, b: &EdwardsPoint, choice: Choice) -> EdwardsPoint {
+        EdwardsPoint {
+            X: ExtF127::conditional_select(&a.X, &b.X, choice),
+            Y: ExtF127::conditional_select(&a.Y, &b.Y, choice),
+            Z: ExtF127::conditional_select(&a.Z, &b.Z, choice),
+            T: ExtF127::conditional_select(&a.T, &b.T, choice),
+        }
+    }
+}
+
+impl PartialEq for EdwardsPoint {
+    fn eq(&self, other: &EdwardsPoint) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for EdwardsPoint {}
+
+impl Add<CachedPoint> for EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: CachedPoint) -> EdwardsPoint {
+        let A = (self.Y - self.X) * other.YmX;
+        let B = (self.Y + self.X) * other.YpX;
+        let C = self.T * other.T2d;
+        let D = self.Z * other.Z2;
+        let E = B - A;
+        let F = D - C;
+        let G = D + C;
+        let H = B + A;
+
+        EdwardsPoint {
+            X: E * F,
+            Y: G * H,
+            Z: F * G,
+            T: E * H,
+        }
+    }
+}
+
+impl Add<EdwardsPoint> for EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn add(self, other: EdwardsPoint) -> EdwardsPoint {
+        self + CachedPoint::from(other)
+    }
+}
+
+impl Sub<EdwardsPoint> for EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn sub(self, other: EdwardsPoint) -> EdwardsPoint {
+        self + (-other)
+    }
+}
+
+impl Neg for EdwardsPoint {
+    type Output = EdwardsPoint;
+    fn neg(self) -> EdwardsPoint {
+        EdwardsPoint {
+            X: -self.X,
+            Y: self.Y,
+            Z: self.Z,
+            T: -self.T,
+        }
+    }
+}
+
+/// The point with \\(y = 4\\), which is not in the subgroup of order
+/// \\(N\\).
+#[cfg(test)]
+pub(crate) fn point_outside_subgroup() -> EdwardsPoint {
+    let mut bytes = [0u8; 32];
+    bytes[0] = 4;
+    CompressedPoint(bytes).decompress().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fourq::constants::FOURQ_GENERATOR;
+
+    /// The order \\(N\\) of the generator, as little-endian words.
+    const ORDER: [u64; 4] = [
+        0x2fb2540ec7768ce7,
+        0xdfbd004dfe0f7999,
+        0xf05397829cbc14e5,
+        0x0029cbc14e5e0a72,
+    ];
+
+    fn affine(x: (u128, u128), y: (u128, u128)) -> EdwardsPoint {
+        let (x, y) = (ExtF127::from(x), ExtF127::from(y));
+        EdwardsPoint {
+            X: x,
+            Y: y,
+            Z: ExtF127::one(),
+            T: x * y,
+        }
+    }
+
+    /// Multiply by a little-endian multiword scalar, by double-and-add.
+    fn mul(P: EdwardsPoint, k: &[u64]) -> EdwardsPoint {
+        let mut Q = EdwardsPoint::identity();
+        for i in (0..64 * k.len()).rev() {
+            Q = Q.double();
+            if (k[i / 64] >> (i % 64)) & 1 == 1 {
+                Q = Q + P;
+            }
+        }
+        Q
+    }
+
+    /// The encodings of \\(G\\), \\(2G\\), and \\(5G\\).
+    const ENCODED_MULTIPLES: [(u64, [u8; 32]); 3] = [
+        (
+            1,
+            [
+                135, 178, 203, 43, 70, 162, 36, 185, 90, 120, 32, 161, 155, 238, 63, 14, 92, 139,
+                76, 132, 68, 195, 167, 73, 66, 2, 14, 99, 248, 74, 28, 110,
+            ],
+        ),
+        (
+            2,
+            [
+                108, 98, 203, 1, 61, 50, 162, 53, 254, 61, 217, 195, 120, 252, 179, 45, 248, 155,
+                145, 226, 37, 149, 124, 238, 82, 84, 1, 138, 185, 76, 192, 68,
+            ],
+        ),
+        (
+            5,
+            [
+                130, 32, 105, 37, 197, 85, 75, 255, 240, 67, 166, 204, 201, 25, 116, 100, 233, 185,
+                95, 242, 39, 23, 26, 62, 9, 104, 116, 228, 243, 129, 245, 133,
+            ],
+        ),
+    ];
+
+    #[test]
+    fn compress_known_answers() {
+        for (k, bytes) in &ENCODED_MULTIPLES {
+            let P = mul(FOURQ_GENERATOR, &[*k]);
+            assert_eq!(P.compress(), CompressedPoint(*bytes));
+            assert_eq!(CompressedPoint(*bytes).decompress().unwrap(), P);
+        }
+
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert_eq!(
+            EdwardsPoint::identity().compress(),
+            CompressedPoint(identity)
+        );
+    }
+
+    #[test]
+    fn compress_roundtrips() {
+        let mut P = FOURQ_GENERATOR;
+        for _ in 0..16 {
+            for Q in &[P, -P, P.double()] {
+                let R = Q.compress().decompress().unwrap();
+                assert_eq!(R, *Q);
+                assert!(R.is_on_curve());
+            }
+            P = P + P.double();
+        }
+
+        let O = EdwardsPoint::identity();
+        assert_eq!(O.compress().decompress().unwrap(), O);
+    }
+
+    #[test]
+    fn decompress_rejects_invalid_encodings() {
+        let p = (1u128 << 127) - 1;
+        let encode = |y0: u128, y1: u128| {
+            let mut bytes = [0u8; 32];
+            bytes[..16].copy_from_slice(&y0.to_le_bytes());
+            bytes[16..].copy_from_slice(&y1.to_le_bytes());
+            CompressedPoint(bytes)
+        };
+        let is_none = |c: CompressedPoint| bool::from(c.decompress().is_none());
+
+        // Non-canonical encodings of y = 1.
+        assert!(is_none(encode(1 + p, 0)));
+        assert!(is_none(encode(1 | (1 << 127), 0)));
+        assert!(is_none(encode(1, p)));
+        // The identity with the sign bit set.
+        assert!(is_none(encode(1, 1 << 127)));
+        // y = 2 gives x^2 = 3/(4d + 1), which is not a square.
+        assert!(is_none(encode(2, 0)));
+        assert!(is_none(encode(2, 1 << 127)));
+    }
+
+    #[test]
+    fn generator_is_on_curve() {
+        assert!(FOURQ_GENERATOR.is_on_curve());
+        assert!(EdwardsPoint::identity().is_on_curve());
+    }
+
+    #[test]
+    fn off_curve_point_is_rejected() {
+        let mut P = FOURQ_GENERATOR;
+        P.Y = P.Y + ExtF127::one();
+        assert!(!P.is_on_curve());
+
+        let mut P = FOURQ_GENERATOR;
+        P.T = P.T + ExtF127::one();
+        assert!(!P.is_on_curve());
+    }
+
+    #[test]
+    fn identity_is_neutral() {
+        let G = FOURQ_GENERATOR;
+        let O = EdwardsPoint::identity();
+        assert_eq!(G + O, G);
+        assert_eq!(O + G, G);
+        assert_eq!(O.double(), O);
+        assert_eq!(O + O, O);
+    }
+
+    #[test]
+    fn double_matches_add() {
+        let G = FOURQ_GENERATOR;
+        let G2 = G.double();
+        assert_eq!(G2, G + G);
+        assert_eq!(G2.double(), G2 + G2);
+        assert!(G2.is_on_curve());
+    }
+
+    #[test]
+    fn negation() {
+        let G = FOURQ_GENERATOR;
+        let O = EdwardsPoint::identity();
+        assert_eq!(G + (-G), O);
+        assert_eq!(G - G, O);
+        assert_eq!(-O, O);
+        assert_ne!(-G, G);
+    }
+
+    #[test]
+    fn equality_is_projective() {
+        let G = FOURQ_GENERATOR;
+        let s = ExtF127::from((
+            38188712660835962328561942614081743514u128,
+            43654918112560223727172090912658261884u128,
+        ));
+        let H = EdwardsPoint {
+            X: G.X * s,
+            Y: G.Y * s,
+            Z: G.Z * s,
+            T: G.T * s,
+        };
+        assert_eq!(G, H);
+        assert_ne!(G, G.double());
+    }
+
+    #[test]
+    fn known_multiples() {
+        let G = FOURQ_GENERATOR;
+
+        let G2 = affine(
+            (
+                43918994774856037492937662037251706435,
+                118073309496132522082669329714988326388,
+            ),
+            (
+                60749801683210776930557441858813518444,
+                91385980873263447417758993666664406008,
+            ),
+        );
+        let G5 = affine(
+            (
+                163683853327808716395398343698744580537,
+                164957169557568830081502636747663974017,
+            ),
+            (
+                133525629062518328487961942413367910530,
+                7920888463251283096179533728744192489,
+            ),
+        );
+        let G1000 = affine(
+            (
+                75330729006416730276777491401187562848,
+                152245847314646623225046201279300778459,
+            ),
+            (
+                148920234766460847660203430072381951869,
+                162757135880602754398001507919381443472,
+            ),
+        );
+
+        assert_eq!(mul(G, &[2]), G2);
+        assert_eq!(mul(G, &[5]), G5);
+        assert_eq!(G2.double() + G, G5);
+        assert_eq!(mul(G, &[1000]), G1000);
+    }
+
+    #[test]
+    fn generator_has_order_n() {
+        let G = FOURQ_GENERATOR;
+        assert_eq!(mul(G, &ORDER), EdwardsPoint::identity());
+
+        let mut n_minus_one = ORDER;
+        n_minus_one[0] -= 1;
+        assert_eq!(mul(G, &n_minus_one), -G);
+    }
+}
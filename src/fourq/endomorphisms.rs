@@ -0,0 +1,323 @@
+//! The FourQ endomorphisms \\(\phi\\) and \\(\psi\\).
+//!
+//! FourQ has two efficiently computable endomorphisms, which act on
+//! the subgroup of order \\(N\\) as multiplication by scalars
+//! \\(\lambda\_\phi\\) and \\(\lambda\_\psi\\) with
+//! $$
+//! \lambda\_\phi^2 \equiv -80, \qquad \lambda\_\psi^2 \equiv 32 \pmod N.
+//! $$
+//! These are the formulas and constants of FourQlib, from Costello and
+//! Longa, "FourQ: four-dimensional decompositions on a Q-curve over the
+//! Mersenne prime".  Both endomorphisms pass through the twisted
+//! Edwards curve \\(\hat E : -x^2 + y^2 = 1 + \hat d x^2 y^2\\) via a
+//! 4-isogeny \\(\tau : E \to \hat E\\) and its dual \\(\hat\tau\\), with
+//! \\(\hat\tau \circ \tau = [4]\\):
+//! $$
+//! \phi = \hat\tau \circ \delta\phi\delta^{-1} \circ \tau, \qquad
+//! \psi = \hat\tau \circ \delta\psi\delta^{-1} \circ \tau,
+//! $$
+//! where \\(\delta\phi\delta^{-1}\\) and \\(\delta\psi\delta^{-1}\\)
+//! are endomorphisms of \\(\hat E\\), written in FourQlib's notation for
+//! the endomorphisms of a Weierstrass model transported to \\(\hat E\\)
+//! by an isomorphism \\(\delta\\).  Both involve the Frobenius map
+//! \\(x \mapsto \bar x\\).
+//!
+//! Each function below follows the operation sequence of the FourQlib
+//! function named in its documentation, and the constants keep their
+//! FourQlib names, so both can be checked against `FourQ_params.h`
+//! and `eccp2_core.c`.  The maps are only meaningful on the subgroup
+//! of order \\(N\\), though they also send the identity to itself.
+
+#![allow(non_snake_case)]
+
+use crate::fourq::EdwardsPoint;
+use crate::serial::{ExtF127, F127};
+
+/// `ctau1`, scaling the \\(x\\)-coordinate of \\(\tau\\).
+const CTAU1: ExtF127 = ExtF127(
+    F127(0x1964de2c3afad20c74dcd57cebce74c3),
+    F127(0x000000000000000c0000000000000012),
+);
+
+/// `ctaudual1`, scaling the \\(x\\)-coordinate of \\(\hat\tau\\).
+const CTAUDUAL1: ExtF127 = ExtF127(
+    F127(0x4aa740eb230586529ecaa6d9decdf034),
+    F127(0x7ffffffffffffff40000000000000011),
+);
+
+/// `cphi0`, scaling the \\(x\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI0: ExtF127 = ExtF127(
+    F127(0x0000000000000005fffffffffffffff7),
+    F127(0x2553a0759182c3294f65536cef66f81a),
+);
+
+/// `cphi1`, in the numerator of the \\(x\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI1: ExtF127 = ExtF127(
+    F127(0x00000000000000050000000000000007),
+    F127(0x62c8caa0c50c62cf334d90e9e28296f9),
+);
+
+/// `cphi2`, in the numerator of the \\(x\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI2: ExtF127 = ExtF127(
+    F127(0x000000000000000f0000000000000015),
+    F127(0x78df262b6c9b5c982c2cb7154f1df391),
+);
+
+/// `cphi3`, in the denominator of the \\(x\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI3: ExtF127 = ExtF127(
+    F127(0x00000000000000020000000000000003),
+    F127(0x5084c6491d76342a92440457a7962ea4),
+);
+
+/// `cphi4`, in the denominator of the \\(x\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI4: ExtF127 = ExtF127(
+    F127(0x00000000000000030000000000000003),
+    F127(0x12440457a7962ea4a1098c923aec6855),
+);
+
+/// `cphi5`, scaling the \\(y\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI5: ExtF127 = ExtF127(
+    F127(0x000000000000000a000000000000000f),
+    F127(0x459195418a18c59e669b21d3c5052df3),
+);
+
+/// `cphi6`, in the numerator of the \\(y\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI6: ExtF127 = ExtF127(
+    F127(0x00000000000000120000000000000018),
+    F127(0x0b232a8314318b3ccd3643a78a0a5be7),
+);
+
+/// `cphi7`, in the numerator of the \\(y\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI7: ExtF127 = ExtF127(
+    F127(0x00000000000000180000000000000023),
+    F127(0x3963bc1c99e2ea1a66c183035f48781a),
+);
+
+/// `cphi8`, in the denominator of the \\(y\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI8: ExtF127 = ExtF127(
+    F127(0x00000000000000aa00000000000000f0),
+    F127(0x1f529f860316cbe544e251582b5d0ef0),
+);
+
+/// `cphi9`, in the denominator of the \\(y\\)-coordinate of \\(\delta\phi\delta^{-1}\\).
+const CPHI9: ExtF127 = ExtF127(
+    F127(0x00000000000008700000000000000bef),
+    F127(0x0fd52e9cfe00375b014d3e48976e2505),
+);
+
+/// `cpsi1`, scaling the \\(x\\)-coordinate of \\(\delta\psi\delta^{-1}\\).
+const CPSI1: ExtF127 = ExtF127(
+    F127(0x2af99e9a83d54a02edf07f4767e346ef),
+    F127(0x00000000000000de000000000000013a),
+);
+
+/// `cpsi2`, in the denominator of the \\(x\\)-coordinate of \\(\delta\psi\delta^{-1}\\).
+const CPSI2: ExtF127 = ExtF127(
+    F127(0x00000000000000e40000000000000143),
+    F127(0x21b8d07b99a81f034c7deb770e03f372),
+);
+
+/// `cpsi3`, in the numerator of the \\(y\\)-coordinate of \\(\delta\psi\delta^{-1}\\).
+const CPSI3: ExtF127 = ExtF127(
+    F127(0x00000000000000060000000000000009),
+    F127(0x4cb26f161d7d69063a6e6abe75e73a61),
+);
+
+/// `cpsi4`, in the denominator of the \\(y\\)-coordinate of \\(\delta\psi\delta^{-1}\\).
+const CPSI4: ExtF127 = ExtF127(
+    F127(0x7ffffffffffffff9fffffffffffffff6),
+    F127(0x334d90e9e28296f9c59195418a18c59e),
+);
+
+/// A point on \\(\hat E\\), in projective coordinates \\((X : Y : Z)\\)
+/// with \\(x = X/Z\\), \\(y = Y/Z\\).
+#[derive(Copy, Clone, Debug)]
+struct HatPoint {
+    X: ExtF127,
+    Y: ExtF127,
+    Z: ExtF127,
+}
+
+/// Compute the 4-isogeny \\(\tau : E \to \hat E\\),
+/// $$
+/// \tau(x, y) = \left( \frac{c x y}{x^2 + y^2},
+/// \frac{x^2 - y^2 + 2}{y^2 - x^2} \right),
+/// $$
+/// with \\(c\\) = `ctau1`, as in FourQlib's `ecc_tau`.
+fn tau(P: &EdwardsPoint) -> HatPoint {
+    let XX = P.X * P.X;
+    let YY = P.Y * P.Y;
+    let ZZ = P.Z * P.Z;
+    let S = XX + YY;
+    let D = YY - XX;
+
+    HatPoint {
+        X: CTAU1 * (P.X * P.Y) * D,
+        Y: (ZZ + ZZ - D) * S,
+        Z: S * D,
+    }
+}
+
+/// Compute the dual 4-isogeny \\(\hat\tau : \hat E \to E\\),
+/// $$
+/// \hat\tau(x, y) = \left( \frac{c x y}{x^2 - y^2 + 2},
+/// \frac{y^2 - x^2}{x^2 + y^2} \right),
+/// $$
+/// with \\(c\\) = `ctaudual1`, as in FourQlib's `ecc_tau_dual`.
+fn tau_dual(P: &HatPoint) -> EdwardsPoint {
+    let XX = P.X * P.X;
+    let YY = P.Y * P.Y;
+    let ZZ = P.Z * P.Z;
+    let Ta = YY - XX;
+    let S = XX + YY;
+    let W = ZZ + ZZ - Ta;
+    let Tb = CTAUDUAL1 * (P.X * P.Y);
+
+    EdwardsPoint {
+        X: Tb * S,
+        Y: W * Ta,
+        Z: W * S,
+        T: Ta * Tb,
+    }
+}
+
+/// Compute the endomorphism \\(\delta\phi\delta^{-1}\\) of \\(\hat
+/// E\\), as in FourQlib's `ecc_delphidel`.
+///
+/// Writing \\(c\_i\\) for `cphi0`, ..., `cphi9`, this is \\((x, y)
+/// \mapsto (\bar x', \bar y')\\) with
+/// $$
+/// x' = c\_0 x \frac{(c\_1 y - y^2 - c\_2)(c\_1 y + y^2 + c\_2)}
+/// {(y^2 + c\_3 y + c\_4)(y^2 - c\_3 y + c\_4)}, \qquad
+/// y' = c\_5 \frac{y^4 + c\_6 y^2 + c\_7}{y (y^4 + c\_8 y^2 + c\_9)}.
+/// $$
+fn delphidel(P: &HatPoint) -> HatPoint {
+    let YY = P.Y * P.Y;
+    let YZ = P.Y * P.Z;
+    let ZZ = P.Z * P.Z;
+
+    let u = YY + CPHI4 * ZZ;
+    let v = CPHI3 * YZ;
+    let denominator = (u + v) * P.Z * (u - v);
+
+    let u = YY + CPHI2 * ZZ;
+    let v = CPHI1 * YZ;
+    let numerator = CPHI0 * ((v - u) * (v + u));
+
+    let Y4 = YY * YY;
+    let Y2Z2 = YZ * YZ;
+    let Z4 = ZZ * ZZ;
+    let E = (Y4 + CPHI8 * Y2Z2 + CPHI9 * Z4) * P.Y;
+    let F = CPHI5 * (Y4 + CPHI6 * Y2Z2 + CPHI7 * Z4);
+
+    HatPoint {
+        X: (P.X * numerator * E).conjugate(),
+        Y: (F * P.Z * denominator).conjugate(),
+        Z: (denominator * E).conjugate(),
+    }
+}
+
+/// Compute the endomorphism \\(\delta\psi\delta^{-1}\\) of \\(\hat
+/// E\\), as in FourQlib's `ecc_delpsidel`.
+///
+/// Writing \\(c\_i\\) for `cpsi1`, ..., `cpsi4`, this is
+/// $$
+/// (x, y) \mapsto \left( \frac{c\_1 \bar x}{\bar y (\bar x^2 + c\_2)},
+/// -\frac{\bar x^2 + c\_3}{\bar x^2 + c\_4} \right).
+/// $$
+fn delpsidel(P: &HatPoint) -> HatPoint {
+    let X = P.X.conjugate();
+    let Y = P.Y.conjugate();
+    let Z = P.Z.conjugate();
+    let XX = X * X;
+    let ZZ = Z * Z;
+
+    let U = Y * (XX + CPSI2 * ZZ);
+    let V = -(XX + CPSI4 * ZZ);
+
+    HatPoint {
+        X: CPSI1 * (X * ZZ) * V,
+        Y: (XX + CPSI3 * ZZ) * U,
+        Z: U * V,
+    }
+}
+
+impl EdwardsPoint {
+    /// Compute \\(\phi(P)\\), which is \\([\lambda\_\phi] P\\) for
+    /// \\(P\\) in the subgroup of order \\(N\\).
+    pub fn phi(&self) -> EdwardsPoint {
+        tau_dual(&delphidel(&tau(self)))
+    }
+
+    /// Compute \\(\psi(P)\\), which is \\([\lambda\_\psi] P\\) for
+    /// \\(P\\) in the subgroup of order \\(N\\).
+    pub fn psi(&self) -> EdwardsPoint {
+        tau_dual(&delpsidel(&tau(self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fourq::constants::FOURQ_GENERATOR;
+    use crate::fourq::scalar::{LAMBDA_PHI, LAMBDA_PSI};
+    use crate::fourq::Scalar;
+
+    /// Multiply by a scalar, by double-and-add.
+    fn mul(P: EdwardsPoint, k: &Scalar) -> EdwardsPoint {
+        let mut Q = EdwardsPoint::identity();
+        for i in (0..256).rev() {
+            Q = Q.double();
+            if (k.0[i / 64] >> (i % 64)) & 1 == 1 {
+                Q = Q + P;
+            }
+        }
+        Q
+    }
+
+    fn points() -> Vec<EdwardsPoint> {
+        let G = FOURQ_GENERATOR;
+        vec![G, G.double(), mul(G, &Scalar::from(0x1234_5678_9abc_def0))]
+    }
+
+    #[test]
+    fn eigenvalues_square_correctly() {
+        assert_eq!(LAMBDA_PHI * LAMBDA_PHI, -Scalar::from(80));
+        assert_eq!(LAMBDA_PSI * LAMBDA_PSI, Scalar::from(32));
+    }
+
+    #[test]
+    fn phi_acts_as_lambda_phi() {
+        for P in points() {
+            let Q = P.phi();
+            assert!(Q.is_on_curve());
+            assert_eq!(Q, mul(P, &LAMBDA_PHI));
+            assert_eq!(Q.phi(), mul(P, &-Scalar::from(80)));
+        }
+    }
+
+    #[test]
+    fn psi_acts_as_lambda_psi() {
+        for P in points() {
+            let Q = P.psi();
+            assert!(Q.is_on_curve());
+            assert_eq!(Q, mul(P, &LAMBDA_PSI));
+            assert_eq!(Q.psi(), mul(P, &Scalar::from(32)));
+        }
+    }
+
+    #[test]
+    fn endomorphisms_commute_on_subgroup() {
+        for P in points() {
+            assert_eq!(P.phi().psi(), P.psi().phi());
+            assert_eq!(P.phi().psi(), mul(P, &(LAMBDA_PHI * LAMBDA_PSI)));
+        }
+    }
+
+    #[test]
+    fn endomorphisms_fix_identity() {
+        let O = EdwardsPoint::identity();
+        assert_eq!(O.phi(), O);
+        assert_eq!(O.psi(), O);
+    }
+}
@@ -0,0 +1,31 @@
+//! The FourQ elliptic curve.
+//!
+//! FourQ is the twisted Edwards curve
+//! $$
+//! E : -x^2 + y^2 = 1 + d x^2 y^2
+//! $$
+//! over \\(\mathbb F\_{p^2} = \mathbb F\_p[i]\\), \\(p = 2^{127} - 1\\),
+//! with the constant \\(d\\) given in `constants`.  Its group of
+//! rational points has order \\(392 N\\), where \\(N\\) is a 246-bit
+//! prime, and the standard generator has order \\(N\\).
+//!
+//! Since \\(d\\) is not a square in \\(\mathbb F\_{p^2}\\), the
+//! Edwards addition law is complete: the same formulas work for every
+//! pair of inputs, including the identity and equal points.
+
+mod basepoint_table;
+pub mod constants;
+mod edwards;
+mod endomorphisms;
+mod multiscalar;
+mod pippenger;
+mod scalar;
+mod variable_base;
+mod vartime_double_base;
+#[cfg(target_feature = "avx2")]
+pub mod vector;
+mod window;
+
+pub use self::basepoint_table::BasepointTable;
+pub use self::edwards::{CompressedPoint, EdwardsPoint};
+pub use self::scalar::Scalar;
@@ -0,0 +1,195 @@
+//! Multiscalar multiplication.
+//!
+//! Both versions compute \\(\sum [k\_i] P\_i\\).  The constant-time
+//! version uses Straus' method: every scalar is decomposed and recoded
+//! as for a single scalar multiplication, and the 65 digits of all the
+//! scalars share one sequence of 64 doublings.  The variable-time
+//! version does the same with non-adjacent forms for small inputs, and
+//! switches to Pippenger's bucket method, in `pippenger`, for large
+//! ones.
+//!
+//! # Panics
+//!
+//! Both functions panic if the scalar and point slices have different
+//! lengths.
+
+#![allow(non_snake_case)]
+
+use subtle::Choice;
+
+use crate::fourq::pippenger::vartime_pippenger;
+use crate::fourq::scalar::{non_adjacent_form, recode, COFACTOR_INVERSE};
+use crate::fourq::variable_base::LookupTable;
+use crate::fourq::window::NafLookupTable5;
+use crate::fourq::{EdwardsPoint, Scalar};
+
+/// The input size from which `vartime_multiscalar_mul` uses
+/// Pippenger's method rather than Straus'.
+const PIPPENGER_THRESHOLD: usize = 32;
+
+impl EdwardsPoint {
+    /// Compute \\(\sum [k\_i] P\_i\\) in constant time.
+    ///
+    /// As with `mul`, the cofactor of every point is cleared first, so
+    /// a point outside the subgroup of order \\(N\\) only contributes
+    /// its component of order \\(N\\).
+    pub fn multiscalar_mul(scalars: &[Scalar], points: &[EdwardsPoint]) -> EdwardsPoint {
+        assert_eq!(scalars.len(), points.len(), "input lengths differ");
+        let (scalars, points) = clear_cofactors(scalars, points);
+
+        let tables: Vec<LookupTable> = points.iter().map(LookupTable::new).collect();
+        let recodings: Vec<_> = scalars.iter().map(|k| recode(&k.decompose())).collect();
+
+        // The top digits are always positive.
+        let mut Q = EdwardsPoint::identity();
+        for (table, (digits, _)) in tables.iter().zip(recodings.iter()) {
+            Q = Q + table.select(digits[64], Choice::from(0));
+        }
+        for i in (0..64).rev() {
+            Q = Q.double();
+            for (table, (digits, signs)) in tables.iter().zip(recodings.iter()) {
+                let is_negative = Choice::from((signs[i] as u8) >> 7);
+                Q = Q + table.select(digits[i], is_negative);
+            }
+        }
+        Q
+    }
+
+    /// Compute \\(\sum [k\_i] P\_i\\) in variable time.
+    ///
+    /// This is intended for public inputs, such as in batch signature
+    /// verification.  The cofactors are cleared as in
+    /// `multiscalar_mul`, so both methods give the same result for
+    /// points outside the subgroup of order \\(N\\).
+    pub fn vartime_multiscalar_mul(scalars: &[Scalar], points: &[EdwardsPoint]) -> EdwardsPoint {
+        assert_eq!(scalars.len(), points.len(), "input lengths differ");
+        let (scalars, points) = clear_cofactors(scalars, points);
+
+        if scalars.len() < PIPPENGER_THRESHOLD {
+            vartime_straus(&scalars, &points)
+        } else {
+            vartime_pippenger(&scalars, &points)
+        }
+    }
+}
+
+/// Replace each \\(P\_i\\) by \\([392] P\_i\\) and each \\(k\_i\\) by
+/// \\(k\_i \cdot 392^{-1}\\), moving the points into the subgroup of
+/// order \\(N\\), where the endomorphisms act as scalars.
+fn clear_cofactors(
+    scalars: &[Scalar],
+    points: &[EdwardsPoint],
+) -> (Vec<Scalar>, Vec<EdwardsPoint>) {
+    let scalars = scalars.iter().map(|k| *k * COFACTOR_INVERSE).collect();
+    let points = points.iter().map(EdwardsPoint::mul_by_cofactor).collect();
+    (scalars, points)
+}
+
+/// Straus' method with width-5 non-adjacent forms of the decomposed
+/// scalars.
+fn vartime_straus(scalars: &[Scalar], points: &[EdwardsPoint]) -> EdwardsPoint {
+    let mut nafs = Vec::with_capacity(4 * scalars.len());
+    let mut tables = Vec::with_capacity(4 * points.len());
+    for (k, P) in scalars.iter().zip(points.iter()) {
+        nafs.extend(k.decompose().iter().map(|&a| non_adjacent_form(a, 5)));
+
+        let P_phi = P.phi();
+        let images = [*P, P_phi, P.psi(), P_phi.psi()];
+        tables.extend(images.iter().map(NafLookupTable5::from));
+    }
+
+    let mut Q = EdwardsPoint::identity();
+    for i in (0..65).rev() {
+        Q = Q.double();
+        for (naf, table) in nafs.iter().zip(tables.iter()) {
+            if naf[i] != 0 {
+                Q = Q + table.select(naf[i]);
+            }
+        }
+    }
+    Q
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fourq::constants::FOURQ_GENERATOR;
+    use crate::fourq::edwards::point_outside_subgroup;
+
+    fn inputs(n: usize) -> (Vec<Scalar>, Vec<EdwardsPoint>) {
+        let mut rng = rand::thread_rng();
+        let scalars = (0..n).map(|_| Scalar::random(&mut rng)).collect();
+        let points = (0..n)
+            .map(|_| FOURQ_GENERATOR.mul(&Scalar::random(&mut rng)))
+            .collect();
+        (scalars, points)
+    }
+
+    fn reference(scalars: &[Scalar], points: &[EdwardsPoint]) -> EdwardsPoint {
+        scalars
+            .iter()
+            .zip(points.iter())
+            .fold(EdwardsPoint::identity(), |sum, (k, P)| sum + P.mul(k))
+    }
+
+    #[test]
+    fn multiscalar_mul_matches_separate_muls() {
+        for &n in &[0, 1, 2, 7] {
+            let (scalars, points) = inputs(n);
+            assert_eq!(
+                EdwardsPoint::multiscalar_mul(&scalars, &points),
+                reference(&scalars, &points)
+            );
+        }
+    }
+
+    #[test]
+    fn vartime_multiscalar_mul_matches_separate_muls() {
+        for &n in &[0, 1, 2, 7, PIPPENGER_THRESHOLD, 300] {
+            let (scalars, points) = inputs(n);
+            let expected = reference(&scalars, &points);
+            assert_eq!(
+                EdwardsPoint::vartime_multiscalar_mul(&scalars, &points),
+                expected
+            );
+            assert_eq!(vartime_straus(&scalars, &points), expected);
+            assert_eq!(vartime_pippenger(&scalars, &points), expected);
+        }
+    }
+
+    #[test]
+    fn multiscalar_mul_outside_subgroup() {
+        for &n in &[7, 300] {
+            let (scalars, mut points) = inputs(n);
+            points[0] = point_outside_subgroup();
+            points[n - 1] = points[n - 1] + point_outside_subgroup();
+
+            let expected = reference(&scalars, &points);
+            assert_eq!(EdwardsPoint::multiscalar_mul(&scalars, &points), expected);
+            assert_eq!(
+                EdwardsPoint::vartime_multiscalar_mul(&scalars, &points),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn multiscalar_mul_edge_cases() {
+        let G = FOURQ_GENERATOR;
+        let O = EdwardsPoint::identity();
+        let scalars = [Scalar::zero(), Scalar::one(), -Scalar::one(), Scalar::one()];
+        let points = [G, G, G.double(), O];
+        let expected = -G;
+
+        assert_eq!(EdwardsPoint::multiscalar_mul(&scalars, &points), expected);
+        assert_eq!(vartime_straus(&scalars, &points), expected);
+        assert_eq!(vartime_pippenger(&scalars, &points), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_lengths_panic() {
+        let (scalars, points) = inputs(2);
+        EdwardsPoint::multiscalar_mul(&scalars, &points[..1]);
+    }
+}
@@ -0,0 +1,66 @@
+//! Variable-time multiscalar multiplication with Pippenger's method.
+//!
+//! Each scalar is written with signed digits in radix \\(2^c\\).  For
+//! each digit position, every point is added to (or subtracted from)
+//! the bucket indexed by the absolute value of its digit, and the
+//! buckets are then combined as \\(\sum\_j j B\_j\\) with a running sum,
+//! costing about \\(2^c\\) additions independently of the number of
+//! points.  The digit positions are combined with \\(c\\) doublings
+//! each.
+//!
+//! This uses the full scalars rather than their decompositions: with
+//! many points, the cost of evaluating the endomorphisms on every
+//! point outweighs the savings in doublings and bucket sums.
+
+#![allow(non_snake_case)]
+
+use crate::fourq::edwards::CachedPoint;
+use crate::fourq::{EdwardsPoint, Scalar};
+
+/// Compute \\(\sum [k\_i] P\_i\\) in variable time.
+pub(crate) fn vartime_pippenger(scalars: &[Scalar], points: &[EdwardsPoint]) -> EdwardsPoint {
+    // Roughly minimize (246 / c) (n + 2^c).
+    let n = scalars.len();
+    let c = ((usize::BITS - n.leading_zeros()) as usize)
+        .saturating_sub(3)
+        .clamp(4, 15);
+
+    let digits: Vec<Vec<i16>> = scalars.iter().map(|k| k.as_radix_2w(c)).collect();
+    let points: Vec<CachedPoint> = points.iter().map(|&P| CachedPoint::from(P)).collect();
+    // The number of digits produced by `as_radix_2w`.
+    let digit_count = 246usize.div_ceil(c) + 1;
+
+    let mut buckets = vec![EdwardsPoint::identity(); 1 << (c - 1)];
+    let mut Q = EdwardsPoint::identity();
+    for i in (0..digit_count).rev() {
+        for _ in 0..c {
+            Q = Q.double();
+        }
+
+        for bucket in buckets.iter_mut() {
+            *bucket = EdwardsPoint::identity();
+        }
+        for (d, P) in digits.iter().zip(points.iter()) {
+            let d = d[i];
+            if d > 0 {
+                let j = (d - 1) as usize;
+                buckets[j] = buckets[j] + *P;
+            } else if d < 0 {
+                let j = (-d - 1) as usize;
+                buckets[j] = buckets[j] + -P;
+            }
+        }
+
+        // Bucket j holds the points with digit j + 1, so adding the
+        // running sum of the buckets from the top down counts each
+        // bucket j + 1 times.
+        let mut running_sum = EdwardsPoint::identity();
+        let mut sum = EdwardsPoint::identity();
+        for bucket in buckets.iter().rev() {
+            running_sum = running_sum + *bucket;
+            sum = sum + running_sum;
+        }
+        Q = Q + sum;
+    }
+    Q
+}
@@ -0,0 +1,884 @@
+//! Integers modulo the order \\(N\\) of the FourQ generator.
+//!
+//! Scalars are stored as four little-endian 64-bit words, always
+//! reduced to the range \\([0, N)\\).  Multiplication uses Montgomery
+//! reduction with \\(R = 2^{256}\\): the Montgomery product
+//! \\(abR^{-1}\\) is multiplied by \\(R^2\\) to cancel the factor of
+//! \\(R^{-1}\\).  Since \\(N < 2^{246}\\), sums and differences of
+//! scalars never carry out of the top word, and the Montgomery
+//! product needs only one final subtraction even when one input is
+//! an unreduced 256-bit integer.
+//!
+//! For scalar multiplication, a scalar \\(k\\) is decomposed as
+//! $$
+//! k \equiv a\_1 + a\_2 \lambda\_\phi + a\_3 \lambda\_\psi + a\_4
+//! \lambda\_\phi \lambda\_\psi \pmod N,
+//! $$
+//! with \\(0 \leq a\_j < 2^{64}\\) and \\(a\_1\\) odd, where
+//! \\(\lambda\_\phi\\) and \\(\lambda\_\psi\\) are the eigenvalues of the
+//! endomorphisms \\(\phi\\) and \\(\psi\\).  Writing \\((k, 0, 0,
+//! 0) = \sum \alpha\_i b\_i\\) in a reduced basis of the lattice of
+//! decompositions of zero, subtracting \\(\sum \lfloor \alpha\_i
+//! \rfloor b\_i\\) leaves a short vector \\(\sum (\alpha\_i - \lfloor
+//! \alpha\_i \rfloor) b\_i\\), which a fixed offset moves into range.
+//! Since the result is known to fit in a word, this can all be
+//! computed modulo \\(2^{64}\\).
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use rand_core::{CryptoRng, RngCore};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// The order \\(N\\) of the FourQ generator.
+const N: [u64; 4] = [
+    0x2fb2540ec7768ce7,
+    0xdfbd004dfe0f7999,
+    0xf05397829cbc14e5,
+    0x0029cbc14e5e0a72,
+];
+
+/// \\(-N^{-1} \bmod 2^{64}\\).
+const N_PRIME: u64 = 0xe12fe5f079bc3929;
+
+/// \\(R \bmod N\\).
+const R: [u64; 4] = [
+    0xdbbd257a49e0f920,
+    0x9a5e224be13735bb,
+    0x0000000000000005,
+    0x0000000000000000,
+];
+
+/// \\(R^2 \bmod N\\).
+const RR: [u64; 4] = [
+    0xc81db8795ff3d621,
+    0x173ea5aaea6b387d,
+    0x3d01b7c72136f61c,
+    0x0006a5f16ac8f9d3,
+];
+
+// The eigenvalues themselves are only needed by tests, since the
+// decomposition constants below encode them.
+
+/// The eigenvalue \\(\lambda\_\phi\\) of \\(\phi\\), with
+/// \\(\lambda\_\phi^2 \equiv -80 \pmod N\\).
+#[cfg(test)]
+pub(crate) const LAMBDA_PHI: Scalar = Scalar([
+    0xcdf0e63ce8997864,
+    0x24874f0b5e4daa9b,
+    0x83c18f03c13d7376,
+    0x0006d9069dc876c8,
+]);
+
+/// The eigenvalue \\(\lambda\_\psi\\) of \\(\psi\\), with
+/// \\(\lambda\_\psi^2 \equiv 32 \pmod N\\).
+#[cfg(test)]
+pub(crate) const LAMBDA_PSI: Scalar = Scalar([
+    0x5dc7f2b7cdfd2a88,
+    0xcb3688ca09ef0e88,
+    0x8c4c2510ab53b51e,
+    0x0018c47535351926,
+]);
+
+/// \\(392^{-1} \bmod N\\), the inverse of the cofactor.
+pub(crate) const COFACTOR_INVERSE: Scalar = Scalar([
+    0x19fbeb877b2691f3,
+    0x5b1f37c5a96f4350,
+    0xa61e1145d66ad5e2,
+    0x0023ee839264702a,
+]);
+
+/// A reduced basis \\(b\_1, \ldots, b\_4\\) for the lattice of
+/// \\((a\_1, a\_2, a\_3, a\_4) \in \mathbb Z^4\\) with
+/// \\(a\_1 + a\_2 \lambda\_\phi + a\_3 \lambda\_\psi + a\_4 \lambda\_\phi
+/// \lambda\_\psi \equiv 0 \pmod N\\), one vector per row, with entries
+/// less than \\(2^{62}\\) in absolute value stored in two's
+/// complement.
+const DECOMPOSITION_BASIS: [[u64; 4]; 4] = [
+    [
+        0x0906ff27e0a0a196,
+        0xec9c179d3dd5d260,
+        0x07426031ecc8030f,
+        0xf7b08c66794619af,
+    ],
+    [
+        0x1d495bea84fcc2d4,
+        0xffffffffffffffff,
+        0x0000000000000001,
+        0x25dbc5bc8dd167d0,
+    ],
+    [
+        0x17abad1d231f0302,
+        0x02c4211ae388da51,
+        0xd1b2de3676d83b61,
+        0x0a9e6f44c02ecd97,
+    ],
+    [
+        0x1c75333271a969d5,
+        0x1dbef6cb01b6d192,
+        0x00b81641c21f595a,
+        0xdedb83ede2680ec5,
+    ],
+];
+
+/// \\(\ell\_i = \mathrm{round}(2^{256} m\_i / N)\\), where \\((1, 0, 0, 0) =
+/// \frac{1}{N} \sum m\_i b\_i\\).  The signs of the basis vectors are
+/// chosen so that the \\(m\_i\\) are positive.
+const DECOMPOSITION_ELL: [[u64; 4]; 4] = [
+    [
+        0x12b1207525327f8f,
+        0x758aa675d20e7fe4,
+        0xe1547d4e47fe61cf,
+        0x0000000000000004,
+    ],
+    [
+        0xd1ba1d84dd627afb,
+        0x2bd235580f468d8d,
+        0x8fd4b04caa6c0f8a,
+        0x0000000000000003,
+    ],
+    [
+        0x9b291a33678c203c,
+        0xc42bd6c965dca902,
+        0xd038bf8d0bffbaf6,
+        0x0000000000000000,
+    ],
+    [
+        0x12e5666b77e7fdc0,
+        0x81cbdc3714983d82,
+        0x1b073877a22d8410,
+        0x0000000000000003,
+    ],
+];
+
+/// A lattice vector \\(c\\) which moves every decomposition into the
+/// range \\([0, 2^{64})\\).
+const DECOMPOSITION_OFFSET: [u64; 4] = [
+    0x42f0d2180b664f9c,
+    0x80c12bd0617bcaad,
+    0x6f4e11186c95f459,
+    0x56889f65d670ed49,
+];
+
+/// A lattice vector \\(c' - c\\) with odd first entry, such that \\(c'\\)
+/// also moves every decomposition into the range \\([0, 2^{64})\\).
+const DECOMPOSITION_PARITY: [u64; 4] = [
+    0xec91cbf56ef737c1,
+    0xcedd20d23c1f00ce,
+    0x068a49f02aa8a9b5,
+    0x18d5087896de0aea,
+];
+
+/// An integer modulo the order \\(N\\) of the FourQ generator.
+#[derive(Copy, Clone, Debug)]
+pub struct Scalar(pub(crate) [u64; 4]);
+
+impl Scalar {
+    /// Constructs the zero scalar.
+    pub fn zero() -> Scalar {
+        Scalar([0; 4])
+    }
+
+    /// Constructs the scalar one.
+    pub fn one() -> Scalar {
+        Scalar([1, 0, 0, 0])
+    }
+
+    /// Encode this scalar as 32 little-endian bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        for (chunk, word) in bytes.chunks_mut(8).zip(self.0.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a scalar from 32 little-endian bytes, returning `None`
+    /// unless they encode an integer less than \\(N\\).
+    pub fn from_canonical_bytes(bytes: &[u8; 32]) -> CtOption<Scalar> {
+        let x = words(bytes);
+        let (_, borrow) = sub_words(&x, &N);
+        CtOption::new(Scalar(x), Choice::from(borrow as u8))
+    }
+
+    /// Reduce a 256-bit little-endian integer modulo \\(N\\).
+    pub fn from_bytes_mod_order(bytes: &[u8; 32]) -> Scalar {
+        // x R / R = x, with the reduction handling x >= N.
+        Scalar(montgomery_mul(&words(bytes), &R))
+    }
+
+    /// Reduce a 512-bit little-endian integer modulo \\(N\\).
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Scalar {
+        let mut lo = [0u8; 32];
+        let mut hi = [0u8; 32];
+        lo.copy_from_slice(&bytes[..32]);
+        hi.copy_from_slice(&bytes[32..]);
+
+        // lo + hi R = (lo R) / R + (hi R^2) / R.
+        let lo = Scalar(montgomery_mul(&words(&lo), &R));
+        let hi = Scalar(montgomery_mul(&words(&hi), &RR));
+        lo + hi
+    }
+
+    /// Sample a uniformly random scalar, by reducing 512 random bits.
+    pub fn random<Rng: RngCore + CryptoRng>(rng: &mut Rng) -> Scalar {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Scalar::from_bytes_mod_order_wide(&bytes)
+    }
+
+    /// Compute the inverse \\(x^{N-2}\\), or zero if \\(x\\) is zero.
+    ///
+    /// The exponent is public, so this runs in constant time.
+    pub fn invert(&self) -> Scalar {
+        let mut e = N;
+        e[0] -= 2;
+
+        // Exponentiate in Montgomery form, starting from R = 1 * R.
+        let x = montgomery_mul(&self.0, &RR);
+        let mut acc = R;
+        for i in (0..256).rev() {
+            acc = montgomery_mul(&acc, &acc);
+            if (e[i / 64] >> (i % 64)) & 1 == 1 {
+                acc = montgomery_mul(&acc, &x);
+            }
+        }
+        Scalar(montgomery_mul(&acc, &[1, 0, 0, 0]))
+    }
+
+    /// Write this scalar with signed digits in radix \\(2^w\\), for
+    /// \\(4 \leq w \leq 15\\).
+    ///
+    /// Returns \\(\lceil 246 / w \rceil + 1\\) digits \\(d\_i\\) with
+    /// \\(k = \sum d\_i 2^{wi}\\) and \\(-2^{w-1} \leq d\_i <
+    /// 2^{w-1}\\).
+    pub(crate) fn as_radix_2w(&self, w: usize) -> Vec<i16> {
+        debug_assert!((4..=15).contains(&w));
+        let count = 246usize.div_ceil(w) + 1;
+        let mask = (1u64 << w) - 1;
+        let half = 1i32 << (w - 1);
+
+        let mut digits = vec![0i16; count];
+        let mut carry = 0i32;
+        for (i, digit) in digits.iter_mut().enumerate().take(count - 1) {
+            let bit = w * i;
+            let (word, shift) = (bit / 64, bit % 64);
+            let next = self.0.get(word + 1).copied().unwrap_or(0);
+            let window = (u128::from(self.0[word]) | (u128::from(next) << 64)) >> shift;
+
+            let x = ((window as u64) & mask) as i32 + carry;
+            carry = (x + half) >> w;
+            *digit = (x - (carry << w)) as i16;
+        }
+        digits[count - 1] = carry as i16;
+        digits
+    }
+
+    /// Decompose this scalar into four 64-bit scalars \\(a\_j\\), with
+    /// \\(a\_1\\) odd, such that \\(k \equiv a\_1 + a\_2 \lambda\_\phi +
+    /// a\_3 \lambda\_\psi + a\_4 \lambda\_\phi \lambda\_\psi \pmod N\\).
+    ///
+    /// This runs in constant time.
+    pub fn decompose(&self) -> [u64; 4] {
+        let mut a = [self.0[0], 0, 0, 0];
+        for (ell, b) in DECOMPOSITION_ELL.iter().zip(DECOMPOSITION_BASIS.iter()) {
+            // The low word of floor(alpha_i), up to a rounding error
+            // which the offset leaves room for.
+            let t = mul_shift_256(&self.0, ell);
+            for (aj, bj) in a.iter_mut().zip(b.iter()) {
+                *aj = aj.wrapping_sub(t.wrapping_mul(*bj));
+            }
+        }
+        for (aj, cj) in a.iter_mut().zip(DECOMPOSITION_OFFSET.iter()) {
+            *aj = aj.wrapping_add(*cj);
+        }
+
+        // If a_1 is even, switch to the other offset.
+        let is_even = (a[0] & 1).wrapping_sub(1);
+        for (aj, dj) in a.iter_mut().zip(DECOMPOSITION_PARITY.iter()) {
+            *aj = aj.wrapping_add(is_even & dj);
+        }
+        a
+    }
+}
+
+/// Recode a decomposition \\((a\_1, a\_2, a\_3, a\_4)\\) with \\(a\_1\\)
+/// odd into 65 signed digits, as in FourQlib.
+///
+/// Returns `(digits, signs)` with `digits[i]` in \\([0, 8)\\) and
+/// `signs[i]` in \\(\\{-1, 1\\}\\), such that
+/// $$
+/// a\_j = \sum\_{i=0}^{64} s\_i d\_{j,i} 2^i,
+/// $$
+/// where \\(d\_{1,i} = 1\\) and \\(d\_{j,i}\\) is bit \\(j - 2\\) of
+/// `digits[i]` for \\(j > 1\\).  So for \\(P\_j\\) the images of
+/// \\(P\\) under \\(1, \phi, \psi, \psi\phi\\), each step of a
+/// scalar multiplication adds \\(\pm (P\_1 + \sum\_{j>1} d\_{j,i} P\_j)\\),
+/// one of eight precomputed points.
+///
+/// The signs come from writing the odd \\(a\_1\\) with digits
+/// \\(s\_i = \pm 1\\): \\(s\_{64} = 1\\), and \\(s\_i = 1\\) for \\(i <
+/// 64\\) exactly when bit \\(i + 1\\) of \\(a\_1\\) is set.  Each other
+/// \\(a\_j\\) is then recoded with digits \\(s\_i d\_{j,i}\\), where
+/// \\(d\_{j,i}\\) is its low bit, carrying into the next position
+/// when \\(s\_i = -1\\).  This runs in constant time.
+pub(crate) fn recode(a: &[u64; 4]) -> ([u8; 65], [i8; 65]) {
+    let mut a = *a;
+    let mut digits = [0u8; 65];
+    let mut signs = [1i8; 65];
+
+    for i in 0..64 {
+        a[0] >>= 1;
+        let s = a[0] & 1;
+        signs[i] = (2 * s as i8) - 1;
+
+        let mut digit = 0;
+        for (j, aj) in a.iter_mut().enumerate().skip(1) {
+            let bit = *aj & 1;
+            // If s_i = -1, subtracting the digit adds the bit.
+            *aj = (*aj >> 1) + (bit & !s);
+            digit |= (bit as u8) << (j - 1);
+        }
+        digits[i] = digit;
+    }
+    digits[64] = (a[1] | (a[2] << 1) | (a[3] << 2)) as u8;
+
+    (digits, signs)
+}
+
+/// Recode a scalar \\(k\\) for the fixed-base comb, with 5 rows of
+/// 50 columns, as in FourQlib's modified LSB-set recoding.
+///
+/// Returns `(digits, signs)` with `digits[j]` in \\([0, 16)\\) and
+/// `signs[j]` in \\(\\{-1, 1\\}\\), such that
+/// $$
+/// k \equiv \sum\_{j=0}^{49} s\_j 2^j \left( 1 + \sum\_{t=1}^{4}
+/// d\_{j,t} 2^{50t} \right) \pmod N,
+/// $$
+/// where \\(d\_{j,t}\\) is bit \\(t - 1\\) of `digits[j]`.
+///
+/// First \\(k\\) is made odd by adding \\(N\\) if necessary, leaving
+/// at most 247 bits.  Its low 50 bits are written with digits \\(s\_j =
+/// \pm 1\\) as in `recode`: \\(s\_{49} = 1\\), and \\(s\_j = 1\\) for
+/// \\(j < 49\\) exactly when bit \\(j + 1\\) is set.  The remaining
+/// high part is recoded a row at a time with digits \\(s\_j
+/// d\_{j,t}\\), carrying into the next position when \\(s\_j = -1\\).
+/// It has at most 197 bits, so since \\(s\_{49} = 1\\) it is used up
+/// after four rows.  This runs in constant time.
+pub(crate) fn recode_comb(k: &Scalar) -> ([u8; 50], [i8; 50]) {
+    let is_even = (k.0[0] & 1).wrapping_sub(1);
+    let k = add_masked(&k.0, &N, is_even);
+
+    let mut digits = [0u8; 50];
+    let mut signs = [1i8; 50];
+    for (j, s) in signs.iter_mut().enumerate().take(49) {
+        let bit = (k[(j + 1) / 64] >> ((j + 1) % 64)) & 1;
+        *s = (2 * bit as i8) - 1;
+    }
+
+    let mut c = [
+        (k[0] >> 50) | (k[1] << 14),
+        (k[1] >> 50) | (k[2] << 14),
+        (k[2] >> 50) | (k[3] << 14),
+        k[3] >> 50,
+    ];
+    for t in 0..4 {
+        for (digit, &s) in digits.iter_mut().zip(signs.iter()) {
+            let bit = c[0] & 1;
+            // If s_j = -1, subtracting the digit adds the bit.
+            let is_negative = ((s as u8) >> 7) as u64;
+            c = add_masked(&c, &[1, 0, 0, 0], 0u64.wrapping_sub(bit & is_negative));
+            for i in 0..3 {
+                c[i] = (c[i] >> 1) | (c[i + 1] << 63);
+            }
+            c[3] >>= 1;
+            *digit |= (bit as u8) << t;
+        }
+    }
+
+    (digits, signs)
+}
+
+/// Compute the width-\\(w\\) non-adjacent form of `a`, for \\(2 \leq w
+/// \leq 8\\).
+///
+/// Returns digits \\(n\_i\\) with \\(a = \sum n\_i 2^i\\), each
+/// either zero or odd with \\(|n\_i| < 2^{w-1}\\), such that any
+/// \\(w\\) consecutive digits include at most one nonzero digit.
+/// This runs in variable time.
+pub(crate) fn non_adjacent_form(a: u64, w: usize) -> [i8; 65] {
+    debug_assert!((2..=8).contains(&w));
+    let width = 1i128 << w;
+    let mut naf = [0i8; 65];
+
+    let mut x = i128::from(a);
+    let mut i = 0;
+    while x != 0 {
+        if x & 1 == 1 {
+            let mut digit = x & (width - 1);
+            if digit >= width / 2 {
+                digit -= width;
+            }
+            naf[i] = digit as i8;
+            x -= digit;
+        }
+        x >>= 1;
+        i += 1;
+    }
+    naf
+}
+
+/// Compute \\(\lfloor xy / 2^{256} \rfloor \bmod 2^{64}\\).
+#[inline]
+fn mul_shift_256(x: &[u64; 4], y: &[u64; 4]) -> u64 {
+    let m = |x: u64, y: u64| (x as u128) * (y as u128);
+
+    let mut z = [0u64; 8];
+    for (i, &xi) in x.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, &yj) in y.iter().enumerate() {
+            let s = (z[i + j] as u128) + m(xi, yj) + carry;
+            z[i + j] = s as u64;
+            carry = s >> 64;
+        }
+        z[i + 4] = carry as u64;
+    }
+    z[4]
+}
+
+/// Load 32 little-endian bytes into words.
+fn words(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut x = [0u64; 4];
+    for (word, chunk) in x.iter_mut().zip(bytes.chunks(8)) {
+        let mut w = [0u8; 8];
+        w.copy_from_slice(chunk);
+        *word = u64::from_le_bytes(w);
+    }
+    x
+}
+
+/// Compute `a - b`, returning the difference modulo \\(2^{256}\\) and
+/// the borrow out (`1` if `a < b`).
+#[inline]
+fn sub_words(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], u64) {
+    let mut z = [0u64; 4];
+    let mut borrow = 0u64;
+    for i in 0..4 {
+        let d = (a[i] as u128)
+            .wrapping_sub(b[i] as u128)
+            .wrapping_sub(borrow as u128);
+        z[i] = d as u64;
+        borrow = ((d >> 64) as u64) & 1;
+    }
+    (z, borrow)
+}
+
+/// Compute `a + (b & mask)`, discarding the carry out.
+#[inline]
+fn add_masked(a: &[u64; 4], b: &[u64; 4], mask: u64) -> [u64; 4] {
+    let mut z = [0u64; 4];
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let s = (a[i] as u128) + ((b[i] & mask) as u128) + carry;
+        z[i] = s as u64;
+        carry = s >> 64;
+    }
+    z
+}
+
+/// Reduce `x < 2N` to the range \\([0, N)\\).
+#[inline]
+fn reduce_once(x: &[u64; 4]) -> [u64; 4] {
+    let (z, borrow) = sub_words(x, &N);
+    // If x < N, the subtraction borrowed, so add N back.
+    add_masked(&z, &N, 0u64.wrapping_sub(borrow))
+}
+
+/// Compute the Montgomery product \\(abR^{-1} \bmod N\\), for `a`
+/// less than \\(2^{256}\\) and `b` less than \\(N\\).
+///
+/// This interleaves the multiplication and reduction word by word.
+/// Before the final subtraction the result is less than
+/// \\((2^{256} N + 2^{256} N) / R = 2N\\).
+#[inline]
+fn montgomery_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let m = |x: u64, y: u64| (x as u128) * (y as u128);
+
+    // t has an extra word, since adding a * b_i can carry past
+    // 2^256.
+    let mut t = [0u64; 5];
+    for &bi in b.iter() {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let s = (t[j] as u128) + m(a[j], bi) + carry;
+            t[j] = s as u64;
+            carry = s >> 64;
+        }
+        let top = (t[4] as u128) + carry;
+
+        // Add k N, with k chosen so that the low word vanishes, and
+        // shift down by one word.
+        let k = t[0].wrapping_mul(N_PRIME);
+        let mut carry = ((t[0] as u128) + m(k, N[0])) >> 64;
+        for j in 1..4 {
+            let s = (t[j] as u128) + m(k, N[j]) + carry;
+            t[j - 1] = s as u64;
+            carry = s >> 64;
+        }
+        let s = top + carry;
+        t[3] = s as u64;
+        t[4] = (s >> 64) as u64;
+    }
+    debug_assert_eq!(t[4], 0);
+
+    reduce_once(&[t[0], t[1], t[2], t[3]])
+}
+
+impl From<u64> for Scalar {
+    fn from(x: u64) -> Scalar {
+        Scalar([x, 0, 0, 0])
+    }
+}
+
+impl ConstantTimeEq for Scalar {
+    fn ct_eq(&self, other: &Scalar) -> Choice {
+        self.0[0].ct_eq(&other.0[0])
+            & self.0[1].ct_eq(&other.0[1])
+            & self.0[2].ct_eq(&other.0[2])
+            & self.0[3].ct_eq(&other.0[3])
+    }
+}
+
+impl PartialEq for Scalar {
+    fn eq(&self, other: &Scalar) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for Scalar {}
+
+impl ConditionallySelectable for Scalar {
+    fn conditional_select(a: &Scalar, b: &Scalar, choice: Choice) -> Scalar {
+        Scalar([
+            u64::conditional_select(&a.0[0], &b.0[0], choice),
+            u64::conditional_select(&a.0[1], &b.0[1], choice),
+            u64::conditional_select(&a.0[2], &b.0[2], choice),
+            u64::conditional_select(&a.0[3], &b.0[3], choice),
+        ])
+    }
+}
+
+impl Add<Scalar> for Scalar {
+    type Output = Scalar;
+    fn add(self, other: Scalar) -> Scalar {
+        // The sum is less than 2N < 2^256, so there is no carry out.
+        Scalar(reduce_once(&add_masked(&self.0, &other.0, !0)))
+    }
+}
+
+impl Sub<Scalar> for Scalar {
+    type Output = Scalar;
+    fn sub(self, other: Scalar) -> Scalar {
+        let (z, borrow) = sub_words(&self.0, &other.0);
+        Scalar(add_masked(&z, &N, 0u64.wrapping_sub(borrow)))
+    }
+}
+
+impl Neg for Scalar {
+    type Output = Scalar;
+    fn neg(self) -> Scalar {
+        Scalar::zero() - self
+    }
+}
+
+impl Mul<Scalar> for Scalar {
+    type Output = Scalar;
+    fn mul(self, other: Scalar) -> Scalar {
+        // (ab / R) R^2 / R = ab.
+        Scalar(montgomery_mul(&montgomery_mul(&self.0, &other.0), &RR))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: Scalar = Scalar([
+        0x493cd01609de8895,
+        0xc33f4584b23bc1d8,
+        0xf59ba79924d8cea5,
+        0x0017ac1176688387,
+    ]);
+
+    const B: Scalar = Scalar([
+        0xb2ccdfa7abf10ac2,
+        0x18a61865cafedacf,
+        0x994b7a5674043590,
+        0x0026f9aa7f8ec4c2,
+    ]);
+
+    #[test]
+    fn mul_vs_sage() {
+        let ab = Scalar([
+            0x53377ca35b51174f,
+            0xb4f68c3f50c33084,
+            0xaaea29f2102941e5,
+            0x001aa3c2c41c2e47,
+        ]);
+        assert_eq!(A * B, ab);
+        assert_eq!(B * A, ab);
+        assert_eq!(A * Scalar::one(), A);
+        assert_eq!(A * Scalar::zero(), Scalar::zero());
+    }
+
+    #[test]
+    fn add_sub_vs_sage() {
+        let a_plus_b = Scalar([
+            0xcc575baeee590670,
+            0xfc285d9c7f2b230e,
+            0x9e938a6cfc20ef4f,
+            0x0014d9faa7993dd7,
+        ]);
+        let a_minus_b = Scalar([
+            0xc622447d25640aba,
+            0x8a562d6ce54c60a1,
+            0x4ca3c4c54d90adfb,
+            0x001a7e284537c938,
+        ]);
+        let b_minus_a = Scalar([
+            0x69900f91a212822d,
+            0x5566d2e118c318f7,
+            0xa3afd2bd4f2b66ea,
+            0x000f4d990926413a,
+        ]);
+
+        assert_eq!(A + B, a_plus_b);
+        assert_eq!(A - B, a_minus_b);
+        assert_eq!(B - A, b_minus_a);
+        assert_eq!(-(A - B), b_minus_a);
+        assert_eq!(A + (-A), Scalar::zero());
+        assert_eq!(-Scalar::zero(), Scalar::zero());
+    }
+
+    #[test]
+    fn invert_vs_sage() {
+        let a_inv = Scalar([
+            0x49a48864f9c8f255,
+            0xeabe9699b07b197e,
+            0xd005791f1e2055c5,
+            0x0006f952c60e353e,
+        ]);
+        assert_eq!(A.invert(), a_inv);
+        assert_eq!(A * a_inv, Scalar::one());
+        assert_eq!(Scalar::zero().invert(), Scalar::zero());
+    }
+
+    #[test]
+    fn wide_reduction_vs_sage() {
+        let bytes: [u8; 64] = [
+            0x44, 0x4c, 0x78, 0x1b, 0xfa, 0xef, 0xf9, 0xd1, 0x62, 0xa0, 0xe2, 0x1f, 0xa0, 0x3f,
+            0x38, 0x60, 0x58, 0xac, 0x49, 0x27, 0xed, 0x9e, 0x82, 0x18, 0xa6, 0x1a, 0x1e, 0x29,
+            0x88, 0xa2, 0x2e, 0x83, 0x02, 0x5c, 0xa2, 0xd5, 0x5b, 0x5d, 0xf8, 0x7e, 0x60, 0xc3,
+            0xf2, 0x20, 0x96, 0x84, 0xb5, 0xc5, 0x5b, 0xb8, 0x08, 0x63, 0x28, 0x35, 0xe3, 0x98,
+            0x4e, 0x59, 0x6b, 0xfa, 0xeb, 0x84, 0xb5, 0x81,
+        ];
+        let reduced = Scalar([
+            0xd3857c4f5c66fee3,
+            0x03f2d82db1daa990,
+            0x1aae72edc93d27b1,
+            0x00144a95d89e9b44,
+        ]);
+        assert_eq!(Scalar::from_bytes_mod_order_wide(&bytes), reduced);
+    }
+
+    #[test]
+    fn canonical_bytes() {
+        assert_eq!(Scalar::from_canonical_bytes(&A.to_bytes()).unwrap(), A);
+        assert_eq!(Scalar::from_bytes_mod_order(&A.to_bytes()), A);
+
+        let n = Scalar(N).to_bytes();
+        assert!(bool::from(Scalar::from_canonical_bytes(&n).is_none()));
+        assert_eq!(Scalar::from_bytes_mod_order(&n), Scalar::zero());
+
+        let max = [0xff; 32];
+        assert!(bool::from(Scalar::from_canonical_bytes(&max).is_none()));
+        let mut wide = [0u8; 64];
+        wide[..32].copy_from_slice(&max);
+        assert_eq!(
+            Scalar::from_bytes_mod_order(&max),
+            Scalar::from_bytes_mod_order_wide(&wide)
+        );
+    }
+
+    /// Check a decomposition and its recoding against `k`.
+    fn check_decomposition(k: &Scalar) {
+        let a = k.decompose();
+        assert_eq!(a[0] & 1, 1);
+
+        let lambda = [
+            Scalar::one(),
+            LAMBDA_PHI,
+            LAMBDA_PSI,
+            LAMBDA_PHI * LAMBDA_PSI,
+        ];
+        let sum = a
+            .iter()
+            .zip(lambda.iter())
+            .fold(Scalar::zero(), |sum, (&aj, &l)| sum + Scalar::from(aj) * l);
+        assert_eq!(sum, *k);
+
+        let (digits, signs) = recode(&a);
+        for (j, &expected) in a.iter().enumerate() {
+            let mut aj = 0i128;
+            for i in (0..65).rev() {
+                let d = if j == 0 {
+                    1
+                } else {
+                    (digits[i] >> (j - 1)) & 1
+                };
+                aj = 2 * aj + i128::from(signs[i]) * i128::from(d);
+            }
+            assert_eq!(aj, i128::from(expected));
+        }
+        assert!(digits.iter().all(|&d| d < 8));
+    }
+
+    #[test]
+    fn decompose_known_answer() {
+        let k = Scalar([
+            0x063151b986aed94d,
+            0x8341f8a4776afbbf,
+            0x0a038c71259c9548,
+            0x0013242b1facb836,
+        ]);
+        assert_eq!(
+            k.decompose(),
+            [
+                0x459b2d65972c4a27,
+                0x4c1a5059bf94a1f5,
+                0x75140953386bf21a,
+                0x83c5d013a0683ff3,
+            ]
+        );
+    }
+
+    #[test]
+    fn decomposition_reconstructs_scalar() {
+        let mut rng = rand::thread_rng();
+        let minus_one = -Scalar::one();
+        let edge_cases = [
+            Scalar::zero(),
+            Scalar::one(),
+            minus_one,
+            minus_one - Scalar::one(),
+            Scalar([0, 0, 0, 1 << 53]),
+            Scalar([!0, !0, !0, (1 << 53) - 1]),
+        ];
+        for k in &edge_cases {
+            check_decomposition(k);
+        }
+        for _ in 0..10_000 {
+            check_decomposition(&Scalar::random(&mut rng));
+        }
+    }
+
+    fn check_comb_recoding(k: &Scalar) {
+        let (digits, signs) = recode_comb(k);
+        assert!(digits.iter().all(|&d| d < 16));
+
+        let two = Scalar::from(2);
+        let mut row_weight = Scalar::one();
+        for _ in 0..50 {
+            row_weight = row_weight * two;
+        }
+
+        let mut sum = Scalar::zero();
+        let mut weight = Scalar::one();
+        for (&d, &s) in digits.iter().zip(signs.iter()) {
+            let mut column = Scalar::one();
+            let mut w = row_weight;
+            for t in 0..4 {
+                column = column + Scalar::from(u64::from((d >> t) & 1)) * w;
+                w = w * row_weight;
+            }
+            let column = column * weight;
+            sum = if s == 1 { sum + column } else { sum - column };
+            weight = weight * two;
+        }
+        assert_eq!(sum, *k);
+    }
+
+    #[test]
+    fn comb_recoding_reconstructs_scalar() {
+        let mut rng = rand::thread_rng();
+        let minus_one = -Scalar::one();
+        let edge_cases = [
+            Scalar::zero(),
+            Scalar::one(),
+            Scalar::from(2),
+            minus_one,
+            minus_one - Scalar::one(),
+        ];
+        for k in &edge_cases {
+            check_comb_recoding(k);
+        }
+        for _ in 0..1000 {
+            check_comb_recoding(&Scalar::random(&mut rng));
+        }
+    }
+
+    #[test]
+    fn non_adjacent_form_reconstructs_value() {
+        let mut rng = rand::thread_rng();
+        let values = (0..1000)
+            .map(|_| rng.next_u64())
+            .chain(vec![0, 1, u64::MAX]);
+        for a in values {
+            for w in 2..=8 {
+                let naf = non_adjacent_form(a, w);
+                let mut x = 0i128;
+                for (i, &n) in naf.iter().enumerate().rev() {
+                    x = 2 * x + i128::from(n);
+                    if n != 0 {
+                        assert_eq!(n & 1, 1);
+                        assert!(i32::from(n).abs() < 1 << (w - 1));
+                        assert!(naf[i + 1..].iter().take(w - 1).all(|&m| m == 0));
+                    }
+                }
+                assert_eq!(x, i128::from(a));
+            }
+        }
+    }
+
+    #[test]
+    fn radix_2w_reconstructs_scalar() {
+        let mut rng = rand::thread_rng();
+        let mut scalars = vec![Scalar::zero(), Scalar::one(), -Scalar::one()];
+        scalars.extend((0..100).map(|_| Scalar::random(&mut rng)));
+        for k in &scalars {
+            for w in 4..=15 {
+                let digits = k.as_radix_2w(w);
+                let radix = Scalar::from(1u64 << w);
+                let mut sum = Scalar::zero();
+                for &d in digits.iter().rev() {
+                    assert!(-(1 << (w - 1)) <= d && i32::from(d) < 1 << (w - 1));
+                    let digit = Scalar::from(u64::from(d.unsigned_abs()));
+                    sum = sum * radix;
+                    sum = if d < 0 { sum - digit } else { sum + digit };
+                }
+                assert_eq!(sum, *k);
+            }
+        }
+    }
+
+    #[test]
+    fn random_scalars_are_reduced() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let x = Scalar::random(&mut rng);
+            assert!(bool::from(
+                Scalar::from_canonical_bytes(&x.to_bytes()).is_some()
+            ));
+            assert_eq!(x * x.invert(), Scalar::one());
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Constant-time variable-base scalar multiplication.
+//!
+//! This follows FourQlib: the scalar is decomposed into four 64-bit
+//! scalars and recoded into 65 signed digits, each selecting one of
+//! the eight points
+//! $$
+//! P + b\_0 \phi(P) + b\_1 \psi(P) + b\_2 \psi(\phi(P)), \qquad
+//! b\_i \in \\{0, 1\\},
+//! $$
+//! up to sign.  The main loop then does one doubling and one addition
+//! per digit, with every table lookup scanning the whole table, so
+//! the sequence of operations and memory accesses does not depend on
+//! the scalar.
+
+#![allow(non_snake_case)]
+
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+
+use crate::fourq::edwards::CachedPoint;
+use crate::fourq::scalar::{recode, COFACTOR_INVERSE};
+use crate::fourq::{EdwardsPoint, Scalar};
+
+/// The eight points \\(P + b\_0 \phi(P) + b\_1 \psi(P) + b\_2
+/// \psi(\phi(P))\\), indexed by \\(b\_0 + 2b\_1 + 4b\_2\\).
+pub(crate) struct LookupTable([CachedPoint; 8]);
+
+impl LookupTable {
+    pub(crate) fn new(P: &EdwardsPoint) -> LookupTable {
+        let Q = P.phi();
+        let R = P.psi();
+        let S = Q.psi();
+
+        let mut points = [*P; 8];
+        points[1] = points[0] + Q;
+        points[2] = points[0] + R;
+        points[3] = points[1] + R;
+        for j in 0..4 {
+            points[j + 4] = points[j] + S;
+        }
+
+        let mut table = [CachedPoint::identity(); 8];
+        for (t, p) in table.iter_mut().zip(points.iter()) {
+            *t = CachedPoint::from(*p);
+        }
+        LookupTable(table)
+    }
+
+    /// Return `self[index]`, negated if `negate` is set, in constant
+    /// time.
+    pub(crate) fn select(&self, index: u8, negate: Choice) -> CachedPoint {
+        let mut t = CachedPoint::identity();
+        for (j, p) in self.0.iter().enumerate() {
+            t.conditional_assign(p, (j as u8).ct_eq(&index));
+        }
+        t.conditional_negate(negate);
+        t
+    }
+}
+
+impl EdwardsPoint {
+    /// Compute \\([k] P\\) in constant time.
+    ///
+    /// The endomorphisms only act as multiplication by their
+    /// eigenvalues on the subgroup of order \\(N\\), so this computes
+    /// \\([k \cdot 392^{-1}] ([392] P)\\).  For \\(P\\) in the
+    /// subgroup, this is \\([k] P\\); otherwise, it is \\([k]\\)
+    /// applied to the component of \\(P\\) of order \\(N\\), with the
+    /// small-order component discarded.
+    pub fn mul(&self, k: &Scalar) -> EdwardsPoint {
+        let table = LookupTable::new(&self.mul_by_cofactor());
+        let (digits, signs) = recode(&(*k * COFACTOR_INVERSE).decompose());
+        let is_negative = |i: usize| Choice::from((signs[i] as u8) >> 7);
+
+        // The top digit is always positive.
+        let mut Q = EdwardsPoint::identity() + table.select(digits[64], Choice::from(0));
+        for i in (0..64).rev() {
+            Q = Q.double() + table.select(digits[i], is_negative(i));
+        }
+        Q
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fourq::constants::FOURQ_GENERATOR;
+    use crate::fourq::edwards::point_outside_subgroup;
+
+    /// Multiply by a scalar, by double-and-add.
+    fn double_and_add(P: EdwardsPoint, k: &Scalar) -> EdwardsPoint {
+        let mut Q = EdwardsPoint::identity();
+        for i in (0..256).rev() {
+            Q = Q.double();
+            if (k.0[i / 64] >> (i % 64)) & 1 == 1 {
+                Q = Q + P;
+            }
+        }
+        Q
+    }
+
+    #[test]
+    fn mul_matches_double_and_add() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..16 {
+            let P = FOURQ_GENERATOR.mul(&Scalar::random(&mut rng));
+            let k = Scalar::random(&mut rng);
+            assert_eq!(P.mul(&k), double_and_add(P, &k));
+        }
+    }
+
+    #[test]
+    fn mul_edge_cases() {
+        let G = FOURQ_GENERATOR;
+        let O = EdwardsPoint::identity();
+        let minus_one = -Scalar::one();
+
+        assert_eq!(G.mul(&Scalar::zero()), O);
+        assert_eq!(G.mul(&Scalar::one()), G);
+        assert_eq!(G.mul(&Scalar::from(2)), G.double());
+        assert_eq!(G.mul(&minus_one), -G);
+        assert_eq!(O.mul(&minus_one), O);
+    }
+
+    #[test]
+    fn mul_outside_subgroup() {
+        let P = point_outside_subgroup();
+
+        // Multiples of the cofactor kill the small-order component, so
+        // they agree with plain double-and-add.
+        let k = Scalar::from(392 * 0x0123_4567_89ab);
+        assert_eq!(P.mul(&k), double_and_add(P, &k));
+        assert_eq!(P.mul(&Scalar::from(392)), P.mul_by_cofactor());
+
+        // Otherwise, the result is the component of order N.
+        let Q = P.mul(&Scalar::one());
+        assert_ne!(Q, P);
+        assert_eq!(Q.mul(&Scalar::one()), Q);
+
+        let P = P.mul_by_cofactor();
+        let k = Scalar::from(0x0123_4567_89ab_cdef);
+        assert_eq!(P.mul(&k), double_and_add(P, &k));
+        assert_eq!(P.mul(&-Scalar::one()), -P);
+    }
+}
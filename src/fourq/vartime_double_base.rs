@@ -0,0 +1,134 @@
+//! Variable-time double-base scalar multiplication.
+//!
+//! Both scalars are decomposed into four 64-bit scalars, giving an
+//! eight-term multiscalar multiplication with about 64 doublings,
+//! which is computed by interleaving the non-adjacent forms of the
+//! eight scalars (Straus' method).  The tables of odd multiples of the
+//! generator and its images under the endomorphisms are precomputed,
+//! so a wider window is used for them.
+
+#![allow(non_snake_case)]
+
+use crate::fourq::constants::FOURQ_BASEPOINT_NAF_TABLES;
+use crate::fourq::scalar::{non_adjacent_form, COFACTOR_INVERSE};
+use crate::fourq::window::NafLookupTable5;
+use crate::fourq::{EdwardsPoint, Scalar};
+
+impl EdwardsPoint {
+    /// Compute \\([a] A + [b] G\\), where \\(G\\) is the FourQ
+    /// generator, in variable time.
+    ///
+    /// This is intended for signature verification, where all inputs
+    /// are public.  As with `mul`, the cofactor of \\(A\\) is cleared
+    /// first, so if \\(A\\) is outside the subgroup of order \\(N\\),
+    /// only its component of order \\(N\\) contributes.
+    pub fn vartime_double_scalar_mul_basepoint(
+        a: &Scalar,
+        A: &EdwardsPoint,
+        b: &Scalar,
+    ) -> EdwardsPoint {
+        let a = *a * COFACTOR_INVERSE;
+        let A = &A.mul_by_cofactor();
+
+        let a_naf = a.decompose().map(|aj| non_adjacent_form(aj, 5));
+        let b_naf = b.decompose().map(|bj| non_adjacent_form(bj, 6));
+
+        let A_phi = A.phi();
+        let A_tables = [A, &A_phi, &A.psi(), &A_phi.psi()].map(NafLookupTable5::from);
+        let B_tables = &FOURQ_BASEPOINT_NAF_TABLES;
+
+        // Find the first nonzero digit.
+        let mut i = 64;
+        while i > 0 && a_naf.iter().chain(b_naf.iter()).all(|naf| naf[i] == 0) {
+            i -= 1;
+        }
+
+        let mut Q = EdwardsPoint::identity();
+        loop {
+            Q = Q.double();
+            for (naf, table) in a_naf.iter().zip(A_tables.iter()) {
+                if naf[i] != 0 {
+                    Q = Q + table.select(naf[i]);
+                }
+            }
+            for (naf, table) in b_naf.iter().zip(B_tables.iter()) {
+                if naf[i] != 0 {
+                    Q = Q + table.select(naf[i]);
+                }
+            }
+
+            if i == 0 {
+                break;
+            }
+            i -= 1;
+        }
+        Q
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fourq::constants::FOURQ_GENERATOR;
+    use crate::fourq::edwards::point_outside_subgroup;
+    use crate::fourq::window::NafLookupTable6;
+
+    #[test]
+    fn static_tables_match_generator() {
+        let G = FOURQ_GENERATOR;
+        let G_phi = G.phi();
+        let images = [G, G_phi, G.psi(), G_phi.psi()];
+        for (P, table) in images.iter().zip(FOURQ_BASEPOINT_NAF_TABLES.iter()) {
+            let expected = NafLookupTable6::from(P);
+            for x in (1..32).step_by(2) {
+                let O = EdwardsPoint::identity();
+                assert_eq!(O + table.select(x), O + expected.select(x));
+                assert_eq!(O + table.select(-x), O + expected.select(-x));
+            }
+        }
+    }
+
+    #[test]
+    fn double_scalar_mul_matches_constant_time() {
+        let mut rng = rand::thread_rng();
+        let G = FOURQ_GENERATOR;
+        for _ in 0..16 {
+            let A = G.mul(&Scalar::random(&mut rng));
+            let a = Scalar::random(&mut rng);
+            let b = Scalar::random(&mut rng);
+            assert_eq!(
+                EdwardsPoint::vartime_double_scalar_mul_basepoint(&a, &A, &b),
+                A.mul(&a) + G.mul(&b)
+            );
+        }
+    }
+
+    #[test]
+    fn double_scalar_mul_outside_subgroup() {
+        let mut rng = rand::thread_rng();
+        let G = FOURQ_GENERATOR;
+        let A = point_outside_subgroup();
+        let a = Scalar::random(&mut rng);
+        let b = Scalar::random(&mut rng);
+        assert_eq!(
+            EdwardsPoint::vartime_double_scalar_mul_basepoint(&a, &A, &b),
+            A.mul(&a) + G.mul(&b)
+        );
+    }
+
+    #[test]
+    fn double_scalar_mul_edge_cases() {
+        let G = FOURQ_GENERATOR;
+        let O = EdwardsPoint::identity();
+        let zero = Scalar::zero();
+        let one = Scalar::one();
+        let mul = EdwardsPoint::vartime_double_scalar_mul_basepoint;
+
+        assert_eq!(mul(&zero, &G, &zero), O);
+        assert_eq!(mul(&one, &G, &zero), G);
+        assert_eq!(mul(&zero, &G, &one), G);
+        assert_eq!(mul(&one, &G, &one), G.double());
+        assert_eq!(mul(&one, &G, &-one), O);
+        assert_eq!(mul(&-one, &O, &-one), -G);
+    }
+}
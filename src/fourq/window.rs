@@ -0,0 +1,67 @@
+//! Tables of odd multiples of a point, for variable-time scalar
+//! multiplication with non-adjacent forms.
+
+#![allow(non_snake_case)]
+
+use crate::fourq::edwards::CachedPoint;
+use crate::fourq::EdwardsPoint;
+
+/// The odd multiples \\([1] P, [3] P, \ldots, [15] P\\), for digits of
+/// a width-5 NAF.
+#[derive(Copy, Clone)]
+pub(crate) struct NafLookupTable5(pub(crate) [CachedPoint; 8]);
+
+/// The odd multiples \\([1] P, [3] P, \ldots, [31] P\\), for digits of
+/// a width-6 NAF.
+#[derive(Copy, Clone)]
+pub(crate) struct NafLookupTable6(pub(crate) [CachedPoint; 16]);
+
+/// Compute the odd multiples of `P` into `table`.
+fn odd_multiples(P: &EdwardsPoint, table: &mut [CachedPoint]) {
+    let P2 = CachedPoint::from(P.double());
+    let mut Q = *P;
+    for entry in table.iter_mut() {
+        *entry = CachedPoint::from(Q);
+        Q = Q + P2;
+    }
+}
+
+impl From<&EdwardsPoint> for NafLookupTable5 {
+    fn from(P: &EdwardsPoint) -> NafLookupTable5 {
+        let mut table = [CachedPoint::identity(); 8];
+        odd_multiples(P, &mut table);
+        NafLookupTable5(table)
+    }
+}
+
+impl From<&EdwardsPoint> for NafLookupTable6 {
+    fn from(P: &EdwardsPoint) -> NafLookupTable6 {
+        let mut table = [CachedPoint::identity(); 16];
+        odd_multiples(P, &mut table);
+        NafLookupTable6(table)
+    }
+}
+
+impl NafLookupTable5 {
+    /// Return \\([x] P\\) for an odd NAF digit `x`, in variable time.
+    pub(crate) fn select(&self, x: i8) -> CachedPoint {
+        select(&self.0, x)
+    }
+}
+
+impl NafLookupTable6 {
+    /// Return \\([x] P\\) for an odd NAF digit `x`, in variable time.
+    pub(crate) fn select(&self, x: i8) -> CachedPoint {
+        select(&self.0, x)
+    }
+}
+
+fn select(table: &[CachedPoint], x: i8) -> CachedPoint {
+    debug_assert_eq!(x & 1, 1);
+    let P = &table[(x.unsigned_abs() / 2) as usize];
+    if x > 0 {
+        *P
+    } else {
+        -P
+    }
+}
@@ -1,40 +1,50 @@
-//! Definitions of (256-bit wide) IFMA intrinsics.
+//! Definitions of (256- and 512-bit wide) IFMA intrinsics.
 //!
-//! Because Rust doesn't have support for AVX-512 yet, we manually
-//! define our own intrinsics that lower to 256-bit IFMA instructions.
-//! This doesn't really have a downside compared to using 512-bit
-//! vectors, because on the only available Cannonlake processor, the
-//! i3-8121U, executes IFMA at 256-bit wide anyways, and this nicely
-//! matches the 4-way parallel Edwards formulas.
-
-use packed_simd::u64x4;
-
-// The `link_name`s below are pulled out of LLVM tablegen, have
-// changed in the past, and might change again in the future.
-#[allow(improper_ctypes)]
-extern "C" {
-    #[link_name = "llvm.x86.avx512.vpmadd52l.uq.256"]
-    fn madd52lo_intrin(z: u64x4, x: u64x4, y: u64x4) -> u64x4;
-    #[link_name = "llvm.x86.avx512.vpmadd52h.uq.256"]
-    fn madd52hi_intrin(z: u64x4, x: u64x4, y: u64x4) -> u64x4;
-}
+//! These wrap the `core::arch` intrinsics for `vpmadd52luq` and
+//! `vpmadd52huq` in functions on the lane-typed vectors.  Each wrapper
+//! enables the target features it needs, so calling one is only safe
+//! from functions that enable them too; elsewhere, calls need an
+//! `unsafe` block, which is sound because this module is only compiled
+//! when the features are enabled for the whole crate.
+//!
+//! The 256-bit forms are used for `F127x4`: on the Cannonlake
+//! i3-8121U, IFMA executes at 256 bits wide anyways, and this nicely
+//! matches the 4-way parallel Edwards formulas.  Later processors
+//! (Ice Lake, Sapphire Rapids, Zen 4) execute IFMA on full 512-bit
+//! vectors, which the 512-bit forms use for `F127x8`.
+
+use core::arch::x86_64::{
+    _mm256_madd52hi_epu64, _mm256_madd52lo_epu64, _mm512_madd52hi_epu64, _mm512_madd52lo_epu64,
+};
+
+use crate::simd::{u64x4, u64x8};
 
-/// A safe wrapper around `vpmadd52luq`.
-///
-/// The intrinsic itself is unsafe because it could generate SIGILL,
-/// but this crate can't be compiled except for IFMA targets.
+/// A wrapper around `vpmadd52luq`.
 #[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
 pub fn madd52lo(z: u64x4, x: u64x4, y: u64x4) -> u64x4 {
-    unsafe { madd52lo_intrin(z, x, y) }
+    u64x4(_mm256_madd52lo_epu64(z.0, x.0, y.0))
 }
 
-/// A safe wrapper around `vpmadd52huq`.
-///
-/// The intrinsic itself is unsafe because it could generate SIGILL,
-/// but this crate can't be compiled except for IFMA targets.
+/// A wrapper around `vpmadd52huq`.
 #[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
 pub fn madd52hi(z: u64x4, x: u64x4, y: u64x4) -> u64x4 {
-    unsafe { madd52hi_intrin(z, x, y) }
+    u64x4(_mm256_madd52hi_epu64(z.0, x.0, y.0))
+}
+
+/// A wrapper around the 512-bit form of `vpmadd52luq`.
+#[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
+pub fn madd52lo_512(z: u64x8, x: u64x8, y: u64x8) -> u64x8 {
+    u64x8(_mm512_madd52lo_epu64(z.0, x.0, y.0))
+}
+
+/// A wrapper around the 512-bit form of `vpmadd52huq`.
+#[inline]
+#[target_feature(enable = "avx512ifma,avx512vl")]
+pub fn madd52hi_512(z: u64x8, x: u64x8, y: u64x8) -> u64x8 {
+    u64x8(_mm512_madd52hi_epu64(z.0, x.0, y.0))
 }
 
 #[cfg(test)]
@@ -47,8 +57,27 @@ mod tests {
         let b = u64x4::splat(3);
         let c = u64x4::new(5, 6, 7, 8);
 
-        let x = madd52lo(a, b, c);
+        // SAFETY: this module is only compiled for IFMA targets.
+        let x = unsafe { madd52lo(a, b, c) };
 
         assert_eq!(x, u64x4::new(1 + 3 * 5, 2 + 3 * 6, 3 + 3 * 7, 4 + 3 * 8));
     }
+
+    #[test]
+    fn test_512_bit_intrinsics() {
+        let a = u64x8::from([1, 2, 3, 4, 5, 6, 7, 8]);
+        let b = u64x8::splat(3);
+        let c = u64x8::from([5, 6, 7, 8, 9, 10, 11, 12]);
+
+        // SAFETY: this module is only compiled for IFMA targets.
+        let (x, y) = unsafe {
+            (
+                madd52lo_512(a, b, c),
+                madd52hi_512(a, u64x8::splat(1 << 51), c),
+            )
+        };
+
+        assert_eq!(x, u64x8::from([16, 20, 24, 28, 32, 36, 40, 44]));
+        assert_eq!(y, u64x8::from([3, 5, 6, 8, 9, 11, 12, 14]));
+    }
 }
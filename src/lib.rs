@@ -2,21 +2,28 @@
 //! prime `2**127 -1`.
 
 //#![no_std]
-#![feature(simd_ffi, link_llvm_intrinsics)]
-#![feature(external_doc)]
 #![deny(missing_docs)]
 
-//#[cfg(not(target_feature = "avx512ifma"))]
-//compile_error!("This crate requires AVX512-IFMA");
+// rustdoc does not see RUSTFLAGS, so skip the check when collecting
+// doctests or building documentation.
+#[cfg(all(
+    feature = "ifma",
+    not(target_feature = "avx512ifma"),
+    not(any(doc, doctest))
+))]
+compile_error!("The `ifma` feature requires building with AVX512-IFMA enabled");
 
-// The `packed_simd` crate contains what would have been the Rust SIMD
-// code, except that it was decided to use untyped Intel __m256i
-// bag-of-bits types instead of nice u64x4 types with arithmetic
-// operations, so we use it instead of core::arch.
-extern crate packed_simd;
+#[cfg(all(target_feature = "avx512ifma", not(target_feature = "avx512vl")))]
+compile_error!("The IFMA backend requires building with AVX512-VL enabled");
 
+pub mod batch;
+pub mod fourq;
 #[cfg(target_feature = "avx512ifma")]
 mod ifma;
+pub mod linalg;
+pub mod poly;
 pub mod serial;
-#[cfg(target_feature = "avx512ifma")]
-mod vector;
+#[cfg(target_feature = "avx2")]
+mod simd;
+#[cfg(target_feature = "avx2")]
+pub mod vector;
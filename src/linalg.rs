@@ -0,0 +1,266 @@
+//! Dense linear algebra over the Mersenne field.
+//!
+//! Matrices are row-major slices of serial field elements.  When the
+//! vector backend is available, products are computed four lanes at
+//! a time and summed lazily: each accumulator is only reduced when
+//! adding another product could overflow its limbs, rather than after
+//! every addition.  Without the vector backend, these fall back to the
+//! serial implementation.
+
+use crate::serial::F127;
+#[cfg(target_feature = "avx2")]
+use crate::vector::{F127x4, LAZY_PRODUCTS};
+
+/// Compute the dot product of `a` and `b`.
+///
+/// # Panics
+///
+/// If `a` and `b` have different lengths.
+pub fn dot(a: &[F127], b: &[F127]) -> F127 {
+    assert_eq!(a.len(), b.len(), "input lengths differ");
+
+    #[cfg(target_feature = "avx2")]
+    {
+        let mut acc = Accumulator::new();
+        for i in (0..a.len()).step_by(4) {
+            acc.add(load(a, i) * load(b, i));
+        }
+        acc.sum_lanes()
+    }
+
+    #[cfg(not(target_feature = "avx2"))]
+    {
+        serial_dot(a.iter().zip(b.iter()))
+    }
+}
+
+/// Compute the matrix-vector product `out = m * v`, where `m` has
+/// `out.len()` rows and `v.len()` columns.
+///
+/// # Panics
+///
+/// If `m` does not have `out.len() * v.len()` entries.
+pub fn mat_vec(m: &[F127], v: &[F127], out: &mut [F127]) {
+    assert_eq!(
+        m.len(),
+        out.len() * v.len(),
+        "matrix dimensions do not match"
+    );
+
+    #[cfg(target_feature = "avx2")]
+    {
+        let v_vec: Vec<F127x4> = (0..v.len()).step_by(4).map(|i| load(v, i)).collect();
+
+        for (r, z) in out.iter_mut().enumerate() {
+            let row = &m[r * v.len()..(r + 1) * v.len()];
+            let mut acc = Accumulator::new();
+            for (j, &y) in v_vec.iter().enumerate() {
+                acc.add(load(row, 4 * j) * y);
+            }
+            *z = acc.sum_lanes();
+        }
+    }
+
+    #[cfg(not(target_feature = "avx2"))]
+    {
+        for (r, z) in out.iter_mut().enumerate() {
+            let row = &m[r * v.len()..(r + 1) * v.len()];
+            *z = serial_dot(row.iter().zip(v.iter()));
+        }
+    }
+}
+
+/// Compute the matrix product `out = a * b`, where `a` has `inner`
+/// columns and `b` has `inner` rows.
+///
+/// # Panics
+///
+/// If `inner` is zero, or if the lengths of `a`, `b`, and `out` are
+/// not consistent with `a` being \\(n \times \mathtt{inner}\\), `b`
+/// being \\(\mathtt{inner} \times m\\), and `out` being \\(n \times
+/// m\\).
+pub fn mat_mul(a: &[F127], b: &[F127], inner: usize, out: &mut [F127]) {
+    assert!(inner > 0, "inner dimension must be nonzero");
+    let rows = a.len() / inner;
+    let cols = b.len() / inner;
+    assert_eq!(a.len(), rows * inner, "matrix dimensions do not match");
+    assert_eq!(b.len(), inner * cols, "matrix dimensions do not match");
+    assert_eq!(out.len(), rows * cols, "matrix dimensions do not match");
+    if out.is_empty() {
+        return;
+    }
+
+    #[cfg(target_feature = "avx2")]
+    {
+        // Convert the rows of b once, padding each to a multiple of
+        // four columns; each row of the output is then a sum of
+        // these, scaled by the entries of a row of a.
+        let chunks = cols.div_ceil(4);
+        let b_vec: Vec<F127x4> = b
+            .chunks(cols)
+            .flat_map(|row| (0..chunks).map(move |j| load(row, 4 * j)))
+            .collect();
+
+        let mut accs = vec![Accumulator::new(); chunks];
+        for (a_row, out_row) in a.chunks(inner).zip(out.chunks_mut(cols)) {
+            for acc in accs.iter_mut() {
+                *acc = Accumulator::new();
+            }
+            for (&x, b_row) in a_row.iter().zip(b_vec.chunks(chunks)) {
+                let x = F127x4::splat(x);
+                for (acc, &y) in accs.iter_mut().zip(b_row) {
+                    acc.add(x * y);
+                }
+            }
+            for (acc, z) in accs.iter().zip(out_row.chunks_mut(4)) {
+                let lanes: (F127, F127, F127, F127) = acc.sum.reduce().into();
+                let lanes = [lanes.0, lanes.1, lanes.2, lanes.3];
+                z.copy_from_slice(&lanes[..z.len()]);
+            }
+        }
+    }
+
+    #[cfg(not(target_feature = "avx2"))]
+    {
+        for (a_row, out_row) in a.chunks(inner).zip(out.chunks_mut(cols)) {
+            for (c, z) in out_row.iter_mut().enumerate() {
+                *z = serial_dot(a_row.iter().zip(b[c..].iter().step_by(cols)));
+            }
+        }
+    }
+}
+
+/// Sum the products of pairs of serial field elements.
+#[cfg(not(target_feature = "avx2"))]
+fn serial_dot<'a, I: Iterator<Item = (&'a F127, &'a F127)>>(pairs: I) -> F127 {
+    pairs.fold(F127::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
+/// Load `xs[i..i + 4]` into a vector, padding with zeros past the end
+/// of `xs`.
+#[cfg(target_feature = "avx2")]
+#[inline]
+fn load(xs: &[F127], i: usize) -> F127x4 {
+    if let Some(x) = xs.get(i..i + 4) {
+        return (x[0], x[1], x[2], x[3]).into();
+    }
+    let x = |j: usize| xs.get(i + j).copied().unwrap_or_default();
+    (x(0), x(1), x(2), x(3)).into()
+}
+
+/// A lazily reduced sum of products.
+#[cfg(target_feature = "avx2")]
+#[derive(Copy, Clone)]
+struct Accumulator {
+    sum: F127x4,
+    /// The number of products added since `sum` was last reduced.
+    pending: usize,
+}
+
+#[cfg(target_feature = "avx2")]
+impl Accumulator {
+    #[inline]
+    fn new() -> Accumulator {
+        Accumulator {
+            sum: F127x4::splat(F127::zero()),
+            pending: 0,
+        }
+    }
+
+    /// Add the product `x` to the sum, reducing first if there is no
+    /// headroom left.
+    #[inline]
+    fn add(&mut self, x: F127x4) {
+        if self.pending == LAZY_PRODUCTS {
+            self.sum = self.sum.reduce();
+            self.pending = 0;
+        }
+        self.sum = self.sum + x;
+        self.pending += 1;
+    }
+
+    /// Sum the four lanes of the accumulator.
+    #[inline]
+    fn sum_lanes(&self) -> F127 {
+        let lanes: (F127, F127, F127, F127) = self.sum.reduce().into();
+        lanes.0 + lanes.1 + lanes.2 + lanes.3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use subtle::ConstantTimeEq;
+
+    use super::*;
+    use crate::serial::elements;
+
+    fn naive_dot(a: &[F127], b: &[F127]) -> F127 {
+        a.iter()
+            .zip(b.iter())
+            .fold(F127::zero(), |acc, (&x, &y)| acc + x * y)
+    }
+
+    #[test]
+    fn dot_matches_naive() {
+        for &n in &[0, 1, 3, 4, 7, 13, 64] {
+            let a = elements(n, 1);
+            let b = elements(n, 2);
+            assert!(bool::from(dot(&a, &b).ct_eq(&naive_dot(&a, &b))));
+        }
+    }
+
+    #[test]
+    fn mat_vec_matches_naive() {
+        for &(rows, cols) in &[(0, 3), (3, 0), (1, 1), (5, 7), (8, 12)] {
+            let m = elements(rows * cols, 3);
+            let v = elements(cols, 4);
+            let mut out = vec![F127::zero(); rows];
+
+            mat_vec(&m, &v, &mut out);
+
+            for (r, &z) in out.iter().enumerate() {
+                let row = &m[r * cols..(r + 1) * cols];
+                assert!(bool::from(z.ct_eq(&naive_dot(row, &v))));
+            }
+        }
+    }
+
+    #[test]
+    fn mat_mul_matches_naive() {
+        for &(rows, inner, cols) in &[(1, 1, 1), (2, 3, 5), (4, 8, 4), (7, 6, 9)] {
+            let a = elements(rows * inner, 5);
+            let b = elements(inner * cols, 6);
+            let mut out = vec![F127::zero(); rows * cols];
+
+            mat_mul(&a, &b, inner, &mut out);
+
+            for r in 0..rows {
+                for c in 0..cols {
+                    let row = &a[r * inner..(r + 1) * inner];
+                    let col: Vec<F127> = b[c..].iter().step_by(cols).copied().collect();
+                    assert!(bool::from(out[r * cols + c].ct_eq(&naive_dot(row, &col))));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(target_feature = "avx512ifma")]
+    fn dot_reduces_when_headroom_runs_out() {
+        // Long enough that each lane of the accumulator is reduced
+        // once partway through.
+        let n = 4 * LAZY_PRODUCTS + 12;
+        let a = elements(n, 7);
+        let b = elements(n, 8);
+        assert!(bool::from(dot(&a, &b).ct_eq(&naive_dot(&a, &b))));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimensions do not match")]
+    fn mismatched_dimensions_panic() {
+        let a = elements(6, 1);
+        let b = elements(6, 2);
+        let mut out = vec![F127::zero(); 5];
+        mat_mul(&a, &b, 3, &mut out);
+    }
+}
@@ -0,0 +1,137 @@
+//! Polynomial evaluation.
+//!
+//! Polynomials are given as slices of coefficients in ascending order,
+//! so that `coeffs[i]` is the coefficient of \\(x^i\\).
+//!
+//! Horner's rule is a single dependent chain of multiplications, so
+//! evaluating at one point is latency-bound.  When the vector backend
+//! is available, `evaluate` splits the polynomial as
+//! $$
+//! p(x) = \sum_{k=0}^{3} x^k p_k(x^4), \qquad
+//! p_k(y) = \sum_j c_{4j+k} y^j,
+//! $$
+//! runs the four Horner chains for the \\(p_k\\) in the lanes of an
+//! `F127x4` with stride \\(x^4\\), and combines the lanes at the end.
+//! `evaluate4` instead runs one Horner chain per lane, at four
+//! different points.  Without the vector backend, both fall back to
+//! serial Horner evaluation.
+
+use crate::serial::F127;
+#[cfg(target_feature = "avx2")]
+use crate::vector::{F127x4, PrecomputedF127x4};
+
+/// Evaluate the polynomial with coefficients `coeffs` at `x`.
+pub fn evaluate(coeffs: &[F127], x: F127) -> F127 {
+    #[cfg(target_feature = "avx2")]
+    {
+        if coeffs.len() > 4 {
+            return evaluate_interleaved(coeffs, x);
+        }
+    }
+
+    horner(coeffs, x)
+}
+
+/// Evaluate the polynomial with coefficients `coeffs` at each of the
+/// four points `xs`.
+pub fn evaluate4(coeffs: &[F127], xs: (F127, F127, F127, F127)) -> (F127, F127, F127, F127) {
+    #[cfg(target_feature = "avx2")]
+    {
+        let x: F127x4 = xs.into();
+        let mut acc = F127x4::splat(F127::zero());
+        for &c in coeffs.iter().rev() {
+            acc = acc * x + F127x4::splat(c);
+        }
+        acc.into()
+    }
+
+    #[cfg(not(target_feature = "avx2"))]
+    {
+        (
+            horner(coeffs, xs.0),
+            horner(coeffs, xs.1),
+            horner(coeffs, xs.2),
+            horner(coeffs, xs.3),
+        )
+    }
+}
+
+/// Serial Horner evaluation.
+fn horner(coeffs: &[F127], x: F127) -> F127 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F127::zero(), |acc, &c| acc * x + c)
+}
+
+/// Evaluate at one point using four interleaved Horner chains.
+#[cfg(target_feature = "avx2")]
+fn evaluate_interleaved(coeffs: &[F127], x: F127) -> F127 {
+    let x2 = x * x;
+    let x3 = x2 * x;
+    let x4 = PrecomputedF127x4::new(F127x4::splat(x2 * x2));
+
+    // Lane k of chunk j holds c_{4j+k}, padding the last chunk with
+    // zeros.
+    let chunk = |j: usize| -> F127x4 {
+        let c = |i: usize| coeffs.get(4 * j + i).copied().unwrap_or_default();
+        (c(0), c(1), c(2), c(3)).into()
+    };
+
+    let chunks = coeffs.len().div_ceil(4);
+    let mut acc = chunk(chunks - 1);
+    for j in (0..chunks - 1).rev() {
+        acc = acc * x4 + chunk(j);
+    }
+
+    let terms: (F127, F127, F127, F127) = (acc * F127x4::from((F127::from(1), x, x2, x3))).into();
+    terms.0 + terms.1 + terms.2 + terms.3
+}
+
+#[cfg(test)]
+mod tests {
+    use subtle::ConstantTimeEq;
+
+    use super::*;
+    use crate::serial::elements;
+
+    /// Evaluate by summing the terms \\(c_i x^i\\) directly.
+    fn naive(coeffs: &[F127], x: F127) -> F127 {
+        let mut power = F127::from(1);
+        let mut sum = F127::zero();
+        for &c in coeffs {
+            sum = sum + c * power;
+            power = power * x;
+        }
+        sum
+    }
+
+    const DEGREES: [usize; 8] = [0, 1, 3, 4, 5, 8, 13, 64];
+
+    #[test]
+    fn evaluate_matches_naive() {
+        let x = F127::from(43654918112560223727172090912658261884u128);
+        for &n in &DEGREES {
+            let coeffs = elements(n, 1);
+            assert!(bool::from(evaluate(&coeffs, x).ct_eq(&naive(&coeffs, x))));
+        }
+    }
+
+    #[test]
+    fn evaluate4_matches_evaluate() {
+        let xs = (
+            F127::from(43654918112560223727172090912658261884u128),
+            F127::from(38188712660835962328561942614081743514u128),
+            F127::zero(),
+            F127::from(1),
+        );
+        for &n in &DEGREES {
+            let coeffs = elements(n, 1);
+            let zs = evaluate4(&coeffs, xs);
+            assert!(bool::from(zs.0.ct_eq(&evaluate(&coeffs, xs.0))));
+            assert!(bool::from(zs.1.ct_eq(&evaluate(&coeffs, xs.1))));
+            assert!(bool::from(zs.2.ct_eq(&evaluate(&coeffs, xs.2))));
+            assert!(bool::from(zs.3.ct_eq(&evaluate(&coeffs, xs.3))));
+        }
+    }
+}
@@ -4,7 +4,122 @@ use super::F127;
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ExtF127(pub(crate) F127, pub(crate) F127);
 
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+impl ExtF127 {
+    /// Constructs the zero element.
+    pub fn zero() -> ExtF127 {
+        ExtF127(F127::zero(), F127::zero())
+    }
+
+    /// Constructs the one element.
+    pub fn one() -> ExtF127 {
+        ExtF127(F127::from(1), F127::zero())
+    }
+
+    /// Compute the conjugate \\(a - bi\\) of \\(a + bi\\).
+    #[inline]
+    pub fn conjugate(&self) -> ExtF127 {
+        ExtF127(self.0, -self.1)
+    }
+
+    /// Compute the norm \\(a^2 + b^2\\) of \\(a + bi\\).
+    #[inline]
+    fn norm(&self) -> F127 {
+        self.0 * self.0 + self.1 * self.1
+    }
+
+    /// Compute the inverse \\(\bar x / N(x)\\), or zero if \\(x\\)
+    /// is zero.
+    pub fn invert(&self) -> ExtF127 {
+        let n = self.norm().invert();
+        ExtF127(self.0 * n, -(self.1 * n))
+    }
+
+    /// Encode this element as 32 bytes, the encodings of its real and
+    /// imaginary parts in that order.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&self.0.to_bytes());
+        bytes[16..].copy_from_slice(&self.1.to_bytes());
+        bytes
+    }
+
+    /// Decode an element from 32 bytes, returning `None` unless both
+    /// halves are canonical encodings.
+    pub fn from_bytes(bytes: &[u8; 32]) -> CtOption<ExtF127> {
+        let mut a = [0u8; 16];
+        let mut b = [0u8; 16];
+        a.copy_from_slice(&bytes[..16]);
+        b.copy_from_slice(&bytes[16..]);
+        let (a, b) = (F127::from_bytes(&a), F127::from_bytes(&b));
+
+        CtOption::new(
+            ExtF127(a.unwrap_or(F127::zero()), b.unwrap_or(F127::zero())),
+            a.is_some() & b.is_some(),
+        )
+    }
+
+    /// Compute a square root of \\(u/v\\), for nonzero \\(v\\).
+    ///
+    /// Returns `(Choice(1), x)` with \\(x^2 = u/v\\) if \\(u/v\\) is
+    /// a square, and `(Choice(0), x)` for some unspecified `x`
+    /// otherwise.
+    ///
+    /// Write \\(u/v = (a + bi)/n\\) with \\(a + bi = u \bar v\\) and
+    /// \\(n = N(v)\\).  A root \\(x_0 + x_1 i\\) has
+    /// \\(x_0^2 - x_1^2 = a/n\\), \\(2 x_0 x_1 = b/n\\), and
+    /// \\(x_0^2 + x_1^2 = \pm s/n\\) with \\(s^2 = a^2 + b^2\\), so
+    /// \\(x_0^2 = \beta/\gamma\\) with \\(\beta = a \pm s\\) and
+    /// \\(\gamma = 2n\\).  Let \\(e = (\beta\gamma)^{(p-3)/4}\\).  If
+    /// \\(\beta\gamma\\) is a square then \\(\beta\gamma e^2 = 1\\) and
+    /// the root is \\(\beta e + bei\\); otherwise
+    /// \\(\beta\gamma e^2 = -1\\) and the root is \\(be - \beta e i\\),
+    /// which corresponds to the other choice of sign.  This takes two
+    /// exponentiations and no inversions.
+    pub(crate) fn sqrt_ratio(u: &ExtF127, v: &ExtF127) -> (Choice, ExtF127) {
+        let w = *u * v.conjugate();
+        let (a, b) = (w.0, w.1);
+        let n = v.norm();
+
+        let s = w.norm().pow_p14();
+        // If b = 0, s may be -a, giving beta = 0; use the other sign
+        // then, which is correct whenever a is nonzero.
+        let beta = a + s;
+        let beta = F127::conditional_select(&beta, &(a - s), beta.ct_eq(&F127::zero()));
+        let gamma = n + n;
+
+        let e = (beta * gamma).pow_p34();
+        let is_square = (beta * gamma * e * e).ct_eq(&F127::from(1));
+        let x = ExtF127::conditional_select(
+            &ExtF127(b * e, -(beta * e)),
+            &ExtF127(beta * e, b * e),
+            is_square,
+        );
+
+        let was_square = (x * x * *v).ct_eq(u);
+        (was_square, x)
+    }
+}
+
+impl ConditionallySelectable for ExtF127 {
+    #[inline]
+    fn conditional_select(a: &ExtF127, b: &ExtF127, choice: Choice) -> ExtF127 {
+        ExtF127(
+            F127::conditional_select(&a.0, &b.0, choice),
+            F127::conditional_select(&a.1, &b.1, choice),
+        )
+    }
+}
+
+impl ConstantTimeEq for ExtF127 {
+    #[inline]
+    fn ct_eq(&self, other: &ExtF127) -> Choice {
+        self.0.ct_eq(&other.0) & self.1.ct_eq(&other.1)
+    }
+}
 
 impl From<(u128, u128)> for ExtF127 {
     #[inline]
@@ -13,10 +128,10 @@ impl From<(u128, u128)> for ExtF127 {
     }
 }
 
-impl Into<(u128, u128)> for ExtF127 {
+impl From<ExtF127> for (u128, u128) {
     #[inline]
-    fn into(self) -> (u128, u128) {
-        (self.0.into(), self.1.into())
+    fn from(x: ExtF127) -> (u128, u128) {
+        (x.0.into(), x.1.into())
     }
 }
 
@@ -36,6 +151,14 @@ impl Sub<ExtF127> for ExtF127 {
     }
 }
 
+impl Neg for ExtF127 {
+    type Output = ExtF127;
+    #[inline]
+    fn neg(self) -> ExtF127 {
+        ExtF127(-self.0, -self.1)
+    }
+}
+
 impl Mul<ExtF127> for ExtF127 {
     type Output = ExtF127;
     #[inline]
@@ -71,4 +194,64 @@ mod tests {
 
         assert_eq!(x * y, z);
     }
+
+    #[test]
+    fn invert_gives_inverse() {
+        let x = ExtF127::from((
+            64602349736890547230188097686032968383u128,
+            58401672467634577377614110902426170573u128,
+        ));
+        assert!(bool::from((x * x.invert()).ct_eq(&ExtF127::one())));
+    }
+
+    #[test]
+    fn bytes_roundtrip() {
+        let x = ExtF127::from((
+            64602349736890547230188097686032968383u128,
+            58401672467634577377614110902426170573u128,
+        ));
+        assert_eq!(ExtF127::from_bytes(&x.to_bytes()).unwrap(), x);
+
+        let mut bytes = x.to_bytes();
+        bytes[31] |= 0x80;
+        assert!(bool::from(ExtF127::from_bytes(&bytes).is_none()));
+    }
+
+    #[test]
+    fn sqrt_ratio_finds_roots() {
+        let v = ExtF127::from((
+            36178516401130528447705023720593931265u128,
+            57463319253223551344966612196770510351u128,
+        ));
+        let xs = [
+            ExtF127::from((
+                64602349736890547230188097686032968383u128,
+                58401672467634577377614110902426170573u128,
+            )),
+            // Purely real and purely imaginary roots exercise b = 0.
+            ExtF127::from((5, 0)),
+            ExtF127::from((0, 5)),
+            ExtF127::zero(),
+        ];
+
+        for x in &xs {
+            let u = *x * *x * v;
+            let (is_square, r) = ExtF127::sqrt_ratio(&u, &v);
+            assert!(bool::from(is_square));
+            assert!(bool::from((r * r * v).ct_eq(&u)));
+        }
+    }
+
+    #[test]
+    fn sqrt_ratio_rejects_nonsquares() {
+        // 2 + i has norm 5, which is not a square mod p, so neither
+        // 2 + i nor its ratio with a square is a square.
+        let u = ExtF127::from((2, 1));
+        let v = ExtF127::from((
+            36178516401130528447705023720593931265u128,
+            57463319253223551344966612196770510351u128,
+        ));
+        let (is_square, _) = ExtF127::sqrt_ratio(&u, &(v * v));
+        assert!(!bool::from(is_square));
+    }
 }
@@ -8,3 +8,17 @@ mod prime_field;
 
 pub use self::ext_field::ExtF127;
 pub use self::prime_field::F127;
+
+/// Deterministic pseudorandom field elements, for tests.
+#[cfg(test)]
+pub(crate) fn elements(n: usize, seed: u128) -> Vec<F127> {
+    let mut state = seed;
+    (0..n)
+        .map(|_| {
+            state = state
+                .wrapping_mul(0x2360ed051fc65da44385df649fccf645)
+                .wrapping_add(0x5851f42d4c957f2d14057b7ef767814f);
+            F127::from(state >> 2)
+        })
+        .collect()
+}
@@ -1,6 +1,8 @@
 //! A serial implementation of the Mersenne field.
 
-use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ops::{Add, Mul, Neg, Sub};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// The Mersenne prime \\(2^{127} - 1\\).
 const P: u128 = (1 << 127) - 1;
@@ -19,6 +21,89 @@ impl F127 {
     pub fn zero() -> F127 {
         F127(0)
     }
+
+    /// The canonical representative of this element, in the range
+    /// \\([0, 2^{127} - 1)\\).
+    #[inline]
+    pub(crate) fn canonical(self) -> u128 {
+        // self.0 + 1 overflows into bit 127 if and only if self.0 = P,
+        // which represents zero.
+        let is_p = (self.0 + 1) >> 127;
+        self.0 & (is_p.wrapping_sub(1))
+    }
+
+    /// Encode this element as 16 little-endian bytes, using its
+    /// canonical representative.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        self.canonical().to_le_bytes()
+    }
+
+    /// Decode an element from 16 little-endian bytes, returning `None`
+    /// unless they encode a canonical representative in \\([0, p)\\).
+    pub fn from_bytes(bytes: &[u8; 16]) -> CtOption<F127> {
+        let x = u128::from_le_bytes(*bytes);
+        // If x < 2^127, then x - P wraps around (setting the high bit)
+        // if and only if x < P.
+        let is_canonical = ((x.wrapping_sub(P) >> 127) & !(x >> 127)) as u8;
+        CtOption::new(F127(x & P), Choice::from(is_canonical))
+    }
+
+    /// Compute \\(x^{2^k}\\) by repeated squaring.
+    #[inline]
+    fn pow2k(self, k: u32) -> F127 {
+        let mut x = self;
+        for _ in 0..k {
+            x = x * x;
+        }
+        x
+    }
+
+    /// Compute \\(x^{(p-3)/4} = x^{2^{125} - 1}\\).
+    ///
+    /// This uses the identity \\(x^{2^{m+n} - 1} = (x^{2^m - 1})^{2^n}
+    /// x^{2^n - 1}\\) to build up the exponent.
+    pub(crate) fn pow_p34(self) -> F127 {
+        let x1 = self;
+        let x2 = x1.pow2k(1) * x1;
+        let x4 = x2.pow2k(2) * x2;
+        let x8 = x4.pow2k(4) * x4;
+        let x16 = x8.pow2k(8) * x8;
+        let x32 = x16.pow2k(16) * x16;
+        let x64 = x32.pow2k(32) * x32;
+        let x96 = x64.pow2k(32) * x32;
+        let x112 = x96.pow2k(16) * x16;
+        let x120 = x112.pow2k(8) * x8;
+        let x124 = x120.pow2k(4) * x4;
+        x124.pow2k(1) * x1
+    }
+
+    /// Compute \\(x^{(p+1)/4} = x^{2^{125}}\\), which is a square
+    /// root of \\(x\\) whenever one exists.
+    pub(crate) fn pow_p14(self) -> F127 {
+        self.pow2k(125)
+    }
+
+    /// Compute the inverse \\(x^{p-2} = (x^{(p-3)/4})^4 x\\), or
+    /// zero if \\(x\\) is zero.
+    pub fn invert(&self) -> F127 {
+        self.pow_p34().pow2k(2) * *self
+    }
+}
+
+impl ConditionallySelectable for F127 {
+    #[inline]
+    fn conditional_select(a: &F127, b: &F127, choice: Choice) -> F127 {
+        F127(u128::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl ConstantTimeEq for F127 {
+    /// Compare canonical representatives, so that \\(0\\) and
+    /// \\(p\\) are equal.
+    #[inline]
+    fn ct_eq(&self, other: &F127) -> Choice {
+        self.canonical().ct_eq(&other.canonical())
+    }
 }
 
 impl Default for F127 {
@@ -50,10 +135,10 @@ impl From<u128> for F127 {
     }
 }
 
-impl Into<u128> for F127 {
+impl From<F127> for u128 {
     #[inline]
-    fn into(self) -> u128 {
-        self.0
+    fn from(x: F127) -> u128 {
+        x.0
     }
 }
 
@@ -193,6 +278,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ct_eq_identifies_zero_and_p() {
+        let x = F127::from(38188712660835962328561942614081743514u128);
+        assert!(bool::from(F127(P).ct_eq(&F127::zero())));
+        assert!(bool::from((x - x).ct_eq(&F127::zero())));
+        assert!(bool::from(x.ct_eq(&x)));
+        assert!(!bool::from(x.ct_eq(&-x)));
+    }
+
+    #[test]
+    fn invert_gives_inverse() {
+        let x = F127::from(38188712660835962328561942614081743514u128);
+        let one = F127::from(1);
+        assert!(bool::from((x * x.invert()).ct_eq(&one)));
+        assert!(bool::from(F127::zero().invert().ct_eq(&F127::zero())));
+    }
+
+    #[test]
+    fn bytes_roundtrip_and_reject_noncanonical() {
+        let x = F127::from(38188712660835962328561942614081743514u128);
+        let y = F127::from_bytes(&x.to_bytes()).unwrap();
+        assert_eq!(y, x);
+
+        // p is the non-canonical encoding of zero.
+        assert_eq!(F127(P).to_bytes(), [0; 16]);
+        assert!(bool::from(F127::from_bytes(&P.to_le_bytes()).is_none()));
+        assert!(bool::from(
+            F127::from_bytes(&u128::MAX.to_le_bytes()).is_none()
+        ));
+        assert!(bool::from(
+            F127::from_bytes(&(1u128 << 127).to_le_bytes()).is_none()
+        ));
+        assert!(bool::from(
+            F127::from_bytes(&(P - 1).to_le_bytes()).is_some()
+        ));
+    }
+
     #[test]
     fn iterated_add() {
         let x = F127::from(38188712660835962328561942614081743514u128);
@@ -0,0 +1,394 @@
+//! Minimal lane-typed wrappers around the `core::arch` vector types.
+//!
+//! The `core::arch` intrinsics operate on untyped `__m256i` and
+//! `__m512i` values, which makes the field arithmetic hard to read.
+//! These wrappers provide just the typed operations the field
+//! arithmetic needs, as operator overloads where possible.
+//!
+//! Every intrinsic is a `#[target_feature]` function, so calling it
+//! is `unsafe` in general.  This module is only compiled when the
+//! required features are enabled for the whole crate, so the calls
+//! below can't generate SIGILL.
+
+#![allow(non_camel_case_types)]
+
+use core::arch::x86_64::*;
+use core::fmt;
+use core::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, Not, Shl, ShlAssign, Shr, Sub};
+
+/// A vector of four `u64` lanes.
+#[derive(Copy, Clone)]
+pub(crate) struct u64x4(pub(crate) __m256i);
+
+/// A vector of four 64-bit lane masks, each either all ones or all
+/// zeros.
+#[derive(Copy, Clone)]
+pub struct m64x4(pub(crate) __m256i);
+
+impl u64x4 {
+    /// Construct a vector from its lanes.
+    #[inline]
+    pub(crate) fn new(x0: u64, x1: u64, x2: u64, x3: u64) -> u64x4 {
+        unsafe {
+            u64x4(_mm256_set_epi64x(
+                x3 as i64, x2 as i64, x1 as i64, x0 as i64,
+            ))
+        }
+    }
+
+    /// Construct a vector with `x` in every lane.
+    #[inline]
+    pub(crate) fn splat(x: u64) -> u64x4 {
+        unsafe { u64x4(_mm256_set1_epi64x(x as i64)) }
+    }
+
+    /// Extract the given `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub(crate) fn extract(self, lane: usize) -> u64 {
+        <[u64; 4]>::from(self)[lane]
+    }
+
+    /// Return a copy of `self` with the given `lane` replaced by `x`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub(crate) fn replace(self, lane: usize, x: u64) -> u64x4 {
+        let mut lanes = <[u64; 4]>::from(self);
+        lanes[lane] = x;
+        lanes.into()
+    }
+
+    /// Compare lanes for equality.
+    #[inline]
+    pub(crate) fn eq(self, other: u64x4) -> m64x4 {
+        unsafe { m64x4(_mm256_cmpeq_epi64(self.0, other.0)) }
+    }
+
+    /// Permute the lanes according to `PATTERN`, as for `vpermq`.
+    #[inline]
+    pub(crate) fn permute<const PATTERN: i32>(self) -> u64x4 {
+        unsafe { u64x4(_mm256_permute4x64_epi64::<PATTERN>(self.0)) }
+    }
+}
+
+impl From<[u64; 4]> for u64x4 {
+    #[inline]
+    fn from(x: [u64; 4]) -> u64x4 {
+        u64x4::new(x[0], x[1], x[2], x[3])
+    }
+}
+
+impl From<u64x4> for [u64; 4] {
+    #[inline]
+    fn from(x: u64x4) -> [u64; 4] {
+        let mut lanes = [0u64; 4];
+        unsafe { _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, x.0) };
+        lanes
+    }
+}
+
+impl PartialEq for u64x4 {
+    fn eq(&self, other: &u64x4) -> bool {
+        <[u64; 4]>::from(*self) == <[u64; 4]>::from(*other)
+    }
+}
+
+impl fmt::Debug for u64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "u64x4({:?})", <[u64; 4]>::from(*self))
+    }
+}
+
+impl m64x4 {
+    /// Construct a mask from its lanes.
+    #[inline]
+    pub fn new(x0: bool, x1: bool, x2: bool, x3: bool) -> m64x4 {
+        let lane = |x: bool| -(x as i64);
+        unsafe { m64x4(_mm256_set_epi64x(lane(x3), lane(x2), lane(x1), lane(x0))) }
+    }
+
+    /// Construct a mask with `x` in every lane.
+    #[inline]
+    pub fn splat(x: bool) -> m64x4 {
+        m64x4::new(x, x, x, x)
+    }
+
+    /// Extract the given `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub fn extract(self, lane: usize) -> bool {
+        assert!(lane < 4, "lane index out of range");
+        (self.bitmask() >> lane) & 1 == 1
+    }
+
+    /// Return the lanes of `self` as the low four bits of an integer.
+    #[inline]
+    pub fn bitmask(self) -> u8 {
+        unsafe { _mm256_movemask_pd(_mm256_castsi256_pd(self.0)) as u8 }
+    }
+
+    /// Check whether every lane is set.
+    #[inline]
+    pub fn all(self) -> bool {
+        self.bitmask() == 0b1111
+    }
+
+    /// Check whether any lane is set.
+    #[inline]
+    pub fn any(self) -> bool {
+        self.bitmask() != 0
+    }
+
+    /// Take lanes from `a` where `self` is set, and from `b` where it
+    /// is not.
+    #[inline]
+    pub(crate) fn select(self, a: u64x4, b: u64x4) -> u64x4 {
+        unsafe { u64x4(_mm256_blendv_epi8(b.0, a.0, self.0)) }
+    }
+}
+
+impl PartialEq for m64x4 {
+    fn eq(&self, other: &m64x4) -> bool {
+        self.bitmask() == other.bitmask()
+    }
+}
+
+impl fmt::Debug for m64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let lanes = [
+            self.extract(0),
+            self.extract(1),
+            self.extract(2),
+            self.extract(3),
+        ];
+        write!(f, "m64x4({:?})", lanes)
+    }
+}
+
+impl BitAnd for m64x4 {
+    type Output = m64x4;
+    #[inline]
+    fn bitand(self, other: m64x4) -> m64x4 {
+        unsafe { m64x4(_mm256_and_si256(self.0, other.0)) }
+    }
+}
+
+impl BitOr for m64x4 {
+    type Output = m64x4;
+    #[inline]
+    fn bitor(self, other: m64x4) -> m64x4 {
+        unsafe { m64x4(_mm256_or_si256(self.0, other.0)) }
+    }
+}
+
+impl Not for m64x4 {
+    type Output = m64x4;
+    #[inline]
+    fn not(self) -> m64x4 {
+        unsafe { m64x4(_mm256_xor_si256(self.0, _mm256_set1_epi64x(-1))) }
+    }
+}
+
+/// Implements the lanewise integer operators for a vector type, using
+/// the given intrinsics.
+macro_rules! impl_ops {
+    ($V:ident, $add:ident, $sub:ident, $and:ident, $or:ident, $sll:ident, $srl:ident) => {
+        impl Add for $V {
+            type Output = $V;
+            #[inline]
+            fn add(self, other: $V) -> $V {
+                unsafe { $V($add(self.0, other.0)) }
+            }
+        }
+
+        impl AddAssign for $V {
+            #[inline]
+            fn add_assign(&mut self, other: $V) {
+                *self = *self + other;
+            }
+        }
+
+        impl Sub for $V {
+            type Output = $V;
+            #[inline]
+            fn sub(self, other: $V) -> $V {
+                unsafe { $V($sub(self.0, other.0)) }
+            }
+        }
+
+        impl BitAnd for $V {
+            type Output = $V;
+            #[inline]
+            fn bitand(self, other: $V) -> $V {
+                unsafe { $V($and(self.0, other.0)) }
+            }
+        }
+
+        impl BitAndAssign for $V {
+            #[inline]
+            fn bitand_assign(&mut self, other: $V) {
+                *self = *self & other;
+            }
+        }
+
+        impl BitOr for $V {
+            type Output = $V;
+            #[inline]
+            fn bitor(self, other: $V) -> $V {
+                unsafe { $V($or(self.0, other.0)) }
+            }
+        }
+
+        impl Shl<u32> for $V {
+            type Output = $V;
+            #[inline]
+            fn shl(self, count: u32) -> $V {
+                unsafe { $V($sll(self.0, _mm_cvtsi32_si128(count as i32))) }
+            }
+        }
+
+        impl ShlAssign<u32> for $V {
+            #[inline]
+            fn shl_assign(&mut self, count: u32) {
+                *self = *self << count;
+            }
+        }
+
+        impl Shr<u32> for $V {
+            type Output = $V;
+            #[inline]
+            fn shr(self, count: u32) -> $V {
+                unsafe { $V($srl(self.0, _mm_cvtsi32_si128(count as i32))) }
+            }
+        }
+    };
+}
+
+impl_ops!(
+    u64x4,
+    _mm256_add_epi64,
+    _mm256_sub_epi64,
+    _mm256_and_si256,
+    _mm256_or_si256,
+    _mm256_sll_epi64,
+    _mm256_srl_epi64
+);
+
+/// A vector of eight `u64` lanes.
+#[cfg(target_feature = "avx512ifma")]
+#[derive(Copy, Clone)]
+pub(crate) struct u64x8(pub(crate) __m512i);
+
+#[cfg(target_feature = "avx512ifma")]
+impl u64x8 {
+    /// Construct a vector with `x` in every lane.
+    #[inline]
+    pub(crate) fn splat(x: u64) -> u64x8 {
+        unsafe { u64x8(_mm512_set1_epi64(x as i64)) }
+    }
+
+    /// Extract the given `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[inline]
+    pub(crate) fn extract(self, lane: usize) -> u64 {
+        <[u64; 8]>::from(self)[lane]
+    }
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl From<[u64; 8]> for u64x8 {
+    #[inline]
+    fn from(x: [u64; 8]) -> u64x8 {
+        unsafe { u64x8(_mm512_loadu_si512(x.as_ptr() as *const __m512i)) }
+    }
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl From<u64x8> for [u64; 8] {
+    #[inline]
+    fn from(x: u64x8) -> [u64; 8] {
+        let mut lanes = [0u64; 8];
+        unsafe { _mm512_storeu_si512(lanes.as_mut_ptr() as *mut __m512i, x.0) };
+        lanes
+    }
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl PartialEq for u64x8 {
+    fn eq(&self, other: &u64x8) -> bool {
+        <[u64; 8]>::from(*self) == <[u64; 8]>::from(*other)
+    }
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl fmt::Debug for u64x8 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "u64x8({:?})", <[u64; 8]>::from(*self))
+    }
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl_ops!(
+    u64x8,
+    _mm512_add_epi64,
+    _mm512_sub_epi64,
+    _mm512_and_si512,
+    _mm512_or_si512,
+    _mm512_sll_epi64,
+    _mm512_srl_epi64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lane_access() {
+        let x = u64x4::new(1, 2, 3, 1 << 63);
+
+        assert_eq!(<[u64; 4]>::from(x), [1, 2, 3, 1 << 63]);
+        assert_eq!(x.extract(3), 1 << 63);
+        assert_eq!(x.replace(1, 7), u64x4::new(1, 7, 3, 1 << 63));
+        assert_eq!(x.permute::<0b00_01_10_11>(), u64x4::new(1 << 63, 3, 2, 1));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let x = u64x4::new(1, 2, 3, 1 << 63);
+        let y = u64x4::splat(3);
+
+        assert_eq!(x + y, u64x4::new(4, 5, 6, (1 << 63) + 3));
+        assert_eq!(x - y, u64x4::new(u64::MAX - 1, u64::MAX, 0, (1 << 63) - 3));
+        assert_eq!(x << 1, u64x4::new(2, 4, 6, 0));
+        assert_eq!(x >> 1, u64x4::new(0, 1, 1, 1 << 62));
+        assert_eq!(x & y, u64x4::new(1, 2, 3, 0));
+        assert_eq!(x | y, u64x4::new(3, 3, 3, (1 << 63) + 3));
+    }
+
+    #[test]
+    fn masks() {
+        let x = u64x4::new(1, 2, 3, 4);
+        let y = u64x4::new(1, 0, 3, 0);
+        let mask = x.eq(y);
+
+        assert_eq!(mask, m64x4::new(true, false, true, false));
+        assert_eq!(!mask, m64x4::new(false, true, false, true));
+        assert_eq!(mask.bitmask(), 0b0101);
+        assert!(mask.any() && !mask.all());
+        assert!((mask | !mask).all());
+        assert!(!(mask & !mask).any());
+        assert_eq!(mask.select(x, u64x4::splat(9)), u64x4::new(1, 9, 3, 9));
+    }
+}
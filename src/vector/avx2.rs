@@ -0,0 +1,712 @@
+//! An AVX2 implementation of `F127x4`, for processors without IFMA.
+//!
+//! AVX2 has no 52-bit multiplier, only `vpmuludq`, which multiplies
+//! the low 32 bits of each 64-bit lane.  So this backend uses five
+//! limbs in the mixed radix
+//! \\[
+//! x = x_0 + x_1 2^{26} + x_2 2^{51} + x_3 2^{77} + x_4 2^{102},
+//! \\]
+//! alternating between \\(26\\) and \\(25\\) bits, so that the limbs
+//! end exactly at \\(2^{127}\\).  Since \\(2^{127} \equiv 1 \pmod
+//! p\\), product terms past the top limb wrap around to the bottom
+//! limb without any multiplication; the only cost of the uneven limb
+//! sizes is that some product terms have a coefficient of \\(2\\).
+//!
+//! When the vector backend is chosen at compile time, this is used
+//! as `vector::F127x4` unless the target supports IFMA.  It is also
+//! available directly from this module, so that it can be tested and
+//! benchmarked on IFMA machines.
+//!
+//! # Limb bounds
+//!
+//! The inputs to a multiplication must have limbs less than
+//! \\(2^{30}\\), which leaves \\(4\\) bits of excess over reduced
+//! limbs (so that \\(16\\) reduced elements can be summed before
+//! multiplying).  Negation subtracts from \\(8p\\), so its input must
+//! have at most \\(3\\) bits of excess, and so must the inputs to
+//! `mul_add2`, which sums two sets of product terms.  As for the IFMA
+//! backend, debug builds track an upper bound on each limb and check
+//! these conditions; see `bounds`.
+
+use core::arch::x86_64::_mm256_mul_epu32;
+use core::ops::{Add, Mul, Neg};
+
+use crate::simd::{m64x4, u64x4};
+
+use crate::serial::F127;
+
+use self::bounds::Bounds;
+
+mod bounds;
+
+/// The number of bits in each limb.
+const WIDTHS: [u32; 5] = [26, 25, 26, 25, 25];
+
+/// The position of the low bit of each limb.
+const OFFSETS: [u32; 5] = [0, 26, 51, 77, 102];
+
+/// The number of products which can be added to a reduced `F127x4`
+/// before it must be reduced again.
+///
+/// The limbs of a product or a reduced element are less than
+/// \\(2^{27}\\), so this leaves several bits of headroom below
+/// \\(2^{64}\\) for the carries in `reduce`.
+#[cfg_attr(target_feature = "avx512ifma", allow(dead_code))]
+pub(crate) const LAZY_PRODUCTS: usize = 1 << 32;
+
+const MASK26: u64 = (1 << 26) - 1;
+const MASK25: u64 = (1 << 25) - 1;
+
+/// A vector of four elements of the Mersenne field.
+#[derive(Copy, Clone)]
+pub struct F127x4([u64x4; 5], Bounds);
+
+/// A compact storage form of an `F127x4`.
+///
+/// This uses the same two-word layout as the IFMA backend: each lane
+/// holds `lo + hi * 2^64`.
+#[derive(Copy, Clone)]
+pub struct PackedF127x4(u64x4, u64x4);
+
+/// A vector of four elements of the Mersenne field, prepared for use
+/// as a fixed multiplier.
+///
+/// This stores the limbs of \\(2y\\) alongside those of \\(y\\), so
+/// that the product terms with a coefficient of \\(2\\) don't need to
+/// be doubled.
+#[derive(Copy, Clone)]
+pub struct PrecomputedF127x4([u64x4; 5], [u64x4; 5], Bounds);
+
+/// A safe wrapper around `vpmuludq`, which computes the 64-bit
+/// products of the low 32 bits of each lane.
+///
+/// The intrinsic itself is unsafe because it could generate SIGILL,
+/// but this module is only compiled for AVX2 targets.
+#[inline]
+fn mul32(x: u64x4, y: u64x4) -> u64x4 {
+    unsafe { u64x4(_mm256_mul_epu32(x.0, y.0)) }
+}
+
+/// Split a reduced element into limbs.
+#[inline]
+fn to_limbs(x: F127) -> [u64; 5] {
+    let mut limbs = [0u64; 5];
+    for (limb, (offset, width)) in limbs.iter_mut().zip(OFFSETS.iter().zip(WIDTHS.iter())) {
+        *limb = ((x.0 >> offset) & ((1 << width) - 1)) as u64;
+    }
+    limbs
+}
+
+impl From<(F127, F127, F127, F127)> for F127x4 {
+    fn from(x: (F127, F127, F127, F127)) -> F127x4 {
+        // Gather the two 64-bit words of each element, then split them
+        // into limbs with vector shifts.
+        let lo = u64x4::new(
+            (x.0).0 as u64,
+            (x.1).0 as u64,
+            (x.2).0 as u64,
+            (x.3).0 as u64,
+        );
+        let hi = u64x4::new(
+            ((x.0).0 >> 64) as u64,
+            ((x.1).0 >> 64) as u64,
+            ((x.2).0 >> 64) as u64,
+            ((x.3).0 >> 64) as u64,
+        );
+
+        PackedF127x4(lo, hi).unpack()
+    }
+}
+
+impl From<F127x4> for (F127, F127, F127, F127) {
+    fn from(x: F127x4) -> (F127, F127, F127, F127) {
+        (x.extract(0), x.extract(1), x.extract(2), x.extract(3))
+    }
+}
+
+impl F127x4 {
+    /// Weakly reduce the limbs of `self`, so that the second limb has
+    /// at most 26 bits and every other limb fits in its radix.
+    #[inline]
+    pub fn reduce(self) -> F127x4 {
+        self.1.check_reducible();
+        let mask26 = u64x4::splat(MASK26);
+        let mask25 = u64x4::splat(MASK25);
+
+        let [mut l0, mut l1, mut l2, mut l3, mut l4] = self.0;
+
+        l1 += l0 >> 26;
+        l0 &= mask26;
+        l2 += l1 >> 25;
+        l1 &= mask25;
+        l3 += l2 >> 26;
+        l2 &= mask26;
+        l4 += l3 >> 25;
+        l3 &= mask25;
+        // Since 2^127 = 1 (mod p), the carry out of the high limb
+        // wraps around to the low limb.
+        l0 += l4 >> 25;
+        l4 &= mask25;
+        l1 += l0 >> 26;
+        l0 &= mask26;
+
+        F127x4(
+            [l0, l1, l2, l3, l4],
+            Bounds::new([MASK26, MASK26, MASK26, MASK25, MASK25]),
+        )
+    }
+
+    /// Construct a vector with `x` in every lane.
+    #[inline]
+    pub fn splat(x: F127) -> F127x4 {
+        (x, x, x, x).into()
+    }
+
+    /// Extract the element in the given `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub fn extract(self, lane: usize) -> F127 {
+        let x = self.reduce();
+
+        // After reduction the lane is less than 2^127 + 2^26, which
+        // is well below 2p.
+        let mut z = 0u128;
+        for (limb, offset) in x.0.iter().zip(OFFSETS.iter()) {
+            z += (limb.extract(lane) as u128) << offset;
+        }
+        F127::from(z)
+    }
+
+    /// Return a copy of `self` with the element in the given `lane`
+    /// replaced by `x`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub fn insert(self, lane: usize, x: F127) -> F127x4 {
+        let x_limbs = to_limbs(x);
+
+        let mut limbs = self.0;
+        for (limb, x_limb) in limbs.iter_mut().zip(x_limbs.iter()) {
+            *limb = limb.replace(lane, *x_limb);
+        }
+        F127x4(limbs, self.1.max(Bounds::reduced()))
+    }
+
+    /// Permute the lanes of `self` according to `PATTERN`.
+    ///
+    /// Lane \\(i\\) of the result is lane `(PATTERN >> 2*i) & 3` of
+    /// `self`, as for `vpermq`.
+    ///
+    /// This is a single permutation instruction on each limb.
+    #[inline]
+    pub fn shuffle<const PATTERN: i32>(self) -> F127x4 {
+        let mut limbs = self.0;
+        for limb in limbs.iter_mut() {
+            *limb = limb.permute::<PATTERN>();
+        }
+        F127x4(limbs, self.1)
+    }
+
+    /// Return a vector whose lanes are taken from `other` where `mask`
+    /// is set, and from `self` where it is not.
+    ///
+    /// This is a single blend instruction on each limb.
+    #[inline]
+    pub fn blend(self, other: F127x4, mask: m64x4) -> F127x4 {
+        let mut limbs = self.0;
+        for (limb, other_limb) in limbs.iter_mut().zip(other.0.iter()) {
+            *limb = mask.select(*other_limb, *limb);
+        }
+        F127x4(limbs, self.1.max(other.1))
+    }
+
+    /// Return a mask of the lanes where `self` and `other` are equal
+    /// as field elements.
+    ///
+    /// Both operands are canonicalized first, so this compares values
+    /// rather than limbs, and it runs in constant time.
+    #[inline]
+    pub fn ct_eq(self, other: F127x4) -> m64x4 {
+        let (x, y) = (self.canonical_limbs(), other.canonical_limbs());
+        x.iter()
+            .zip(y.iter())
+            .fold(m64x4::splat(true), |eq, (&xi, &yi)| eq & xi.eq(yi))
+    }
+
+    /// Return a mask of the lanes where `self` is zero, in constant
+    /// time.
+    #[inline]
+    pub fn is_zero(self) -> m64x4 {
+        let zero = u64x4::splat(0);
+        self.canonical_limbs()
+            .iter()
+            .fold(m64x4::splat(true), |eq, &xi| eq & xi.eq(zero))
+    }
+
+    /// Compute the limbs of the canonical representative of each lane,
+    /// in \\([0, p)\\).
+    #[inline]
+    fn canonical_limbs(self) -> [u64x4; 5] {
+        let masks = WIDTHS.map(|w| u64x4::splat((1 << w) - 1));
+        let mut l = self.reduce().0;
+
+        // After reduction only the second limb can exceed its radix,
+        // and only by one.  A carry pass leaves every limb within its
+        // radix except that the low limb may be 2^26; a second pass
+        // only wraps around if it carries out of every limb, leaving
+        // them zero, so afterwards every limb is within its radix.
+        for _ in 0..2 {
+            for i in 0..4 {
+                l[i + 1] += l[i] >> WIDTHS[i];
+                l[i] &= masks[i];
+            }
+            l[0] += l[4] >> WIDTHS[4];
+            l[4] &= masks[4];
+        }
+
+        // Now each lane is in [0, p], and p has every bit set.
+        let is_p = l
+            .iter()
+            .zip(masks.iter())
+            .fold(m64x4::splat(true), |eq, (&li, &mask)| eq & li.eq(mask));
+        let zero = u64x4::splat(0);
+
+        l.map(|li| is_p.select(zero, li))
+    }
+
+    /// Convert `self` to its compact storage form.
+    #[inline]
+    pub fn pack(self) -> PackedF127x4 {
+        // After reduction only the second limb can overflow its
+        // radix, so carry it up through the other limbs.  Afterwards
+        // every limb fits in its radix, except that the top limb may
+        // be 2^25, so the limbs occupy disjoint bits of lo + hi * 2^64.
+        let [l0, mut l1, mut l2, mut l3, mut l4] = self.reduce().0;
+
+        l2 += l1 >> 25;
+        l1 &= u64x4::splat(MASK25);
+        l3 += l2 >> 26;
+        l2 &= u64x4::splat(MASK26);
+        l4 += l3 >> 25;
+        l3 &= u64x4::splat(MASK25);
+
+        let lo = l0 | (l1 << 26) | (l2 << 51);
+        let hi = (l2 >> 13) | (l3 << 13) | (l4 << 38);
+
+        PackedF127x4(lo, hi)
+    }
+}
+
+impl PackedF127x4 {
+    /// Convert `self` to the five-limb form used for arithmetic.
+    #[inline]
+    pub fn unpack(self) -> F127x4 {
+        let mask13 = u64x4::splat((1 << 13) - 1);
+        let mask25 = u64x4::splat(MASK25);
+        let mask26 = u64x4::splat(MASK26);
+
+        let (lo, hi) = (self.0, self.1);
+
+        // The top limb gets the remaining 26 bits, which is within the
+        // allowed excess.
+        F127x4(
+            [
+                lo & mask26,
+                (lo >> 26) & mask25,
+                (lo >> 51) | ((hi & mask13) << 13),
+                (hi >> 13) & mask25,
+                hi >> 38,
+            ],
+            Bounds::new([MASK26, MASK25, MASK26, MASK25, MASK26]),
+        )
+    }
+}
+
+impl Neg for F127x4 {
+    type Output = F127x4;
+    #[inline]
+    fn neg(self) -> F127x4 {
+        const P_TIMES_8: [u64; 5] = [
+            MASK26 << 3,
+            MASK25 << 3,
+            MASK26 << 3,
+            MASK25 << 3,
+            MASK25 << 3,
+        ];
+
+        let mut limbs = self.0;
+        for (limb, p) in limbs.iter_mut().zip(P_TIMES_8.iter()) {
+            *limb = u64x4::splat(*p) - *limb;
+        }
+        F127x4(limbs, self.1.sub_from(P_TIMES_8))
+    }
+}
+
+impl Add<F127x4> for F127x4 {
+    type Output = F127x4;
+    #[inline]
+    fn add(self, other: F127x4) -> F127x4 {
+        let mut limbs = self.0;
+        for (limb, other_limb) in limbs.iter_mut().zip(other.0.iter()) {
+            *limb += *other_limb;
+        }
+        F127x4(limbs, self.1.add(other.1))
+    }
+}
+
+impl Mul<F127x4> for F127x4 {
+    type Output = F127x4;
+    #[inline]
+    fn mul(self, other: F127x4) -> F127x4 {
+        let bounds = self.1.mul(other.1);
+        carry_product(products(self, other), bounds)
+    }
+}
+
+impl F127x4 {
+    /// Compute the products `x.0 * x.1` and `y.0 * y.1`.
+    ///
+    /// The two multiplications are independent, so interleaving them
+    /// overlaps the carries at the end of one with the work of the
+    /// other.
+    #[inline]
+    pub fn mul2(x: (F127x4, F127x4), y: (F127x4, F127x4)) -> (F127x4, F127x4) {
+        let bounds = ((x.0).1.mul((x.1).1), (y.0).1.mul((y.1).1));
+        let z = products(x.0, x.1);
+        let w = products(y.0, y.1);
+
+        (carry_product(z, bounds.0), carry_product(w, bounds.1))
+    }
+
+    /// Compute `x.0 * x.1 + y.0 * y.1`.
+    ///
+    /// This interleaves the two multiplications like `mul2`, but sums
+    /// their product terms before carrying, so that only one carry
+    /// step is needed.  Since the sums must still fit in 64 bits, the
+    /// inputs must have limbs less than \\(2^{29}\\), rather than
+    /// \\(2^{30}\\).
+    #[inline]
+    pub fn mul_add2(x: (F127x4, F127x4), y: (F127x4, F127x4)) -> F127x4 {
+        let bounds = Bounds::mul_add2(((x.0).1, (x.1).1), ((y.0).1, (y.1).1));
+        let z = products(x.0, x.1);
+        let w = products(y.0, y.1);
+
+        let mut sum = z;
+        for (zi, wi) in sum.iter_mut().zip(w.iter()) {
+            *zi += *wi;
+        }
+        carry_product(sum, bounds)
+    }
+}
+
+/// Compute the product terms of `x * y`, before carrying.
+#[inline]
+fn products(x: F127x4, y: F127x4) -> [u64x4; 5] {
+    let [x0, x1, x2, x3, x4] = x.0;
+    let [y0, y1, y2, y3, y4] = y.0;
+
+    let m = mul32;
+
+    // The term x_i y_j lands at 2^(o_i + o_j), which is either at
+    // the start of limb (i + j) mod 5 or one bit past it (after
+    // wrapping 2^127 to 1).  Collect the terms with coefficient 2
+    // separately and double them once.
+    //
+    // With limbs below 2^30, each product is below 2^60, and each
+    // z_k below 2^63.2.
+    let z0 = m(x0, y0) + ((m(x1, y4) + m(x4, y1) + m(x2, y3) + m(x3, y2)) << 1);
+    let z1 = m(x0, y1) + m(x1, y0) + m(x2, y4) + m(x4, y2) + (m(x3, y3) << 1);
+    let z2 = m(x0, y2) + m(x2, y0) + ((m(x1, y1) + m(x3, y4) + m(x4, y3)) << 1);
+    let z3 = m(x0, y3) + m(x3, y0) + m(x1, y2) + m(x2, y1) + m(x4, y4);
+    let z4 = m(x0, y4) + m(x4, y0) + m(x2, y2) + ((m(x1, y3) + m(x3, y1)) << 1);
+
+    [z0, z1, z2, z3, z4]
+}
+
+/// Carry the accumulated product terms `z` (each less than 2^64) into
+/// an element with limbs of at most 26 bits, whose limbs have the given
+/// `bounds`.
+#[inline]
+fn carry_product(z: [u64x4; 5], bounds: Bounds) -> F127x4 {
+    // Carry in two interleaved chains, z0 -> z1 -> z2 -> z3 and
+    // z3 -> z4 -> z0 -> z1, to shorten the dependency chain.
+    let mask26 = u64x4::splat(MASK26);
+    let mask25 = u64x4::splat(MASK25);
+
+    let [mut z0, mut z1, mut z2, mut z3, mut z4] = z;
+
+    z1 += z0 >> 26;
+    z0 &= mask26;
+    z4 += z3 >> 25;
+    z3 &= mask25;
+
+    z2 += z1 >> 25;
+    z1 &= mask25;
+    z0 += z4 >> 25;
+    z4 &= mask25;
+
+    z3 += z2 >> 26;
+    z2 &= mask26;
+    z1 += z0 >> 26;
+    z0 &= mask26;
+
+    z4 += z3 >> 25;
+    z3 &= mask25;
+
+    F127x4([z0, z1, z2, z3, z4], bounds)
+}
+
+impl PrecomputedF127x4 {
+    /// Prepare `y` for use as a fixed multiplier.
+    pub fn new(y: F127x4) -> PrecomputedF127x4 {
+        let F127x4(y, bounds) = y.reduce();
+
+        let mut y2 = y;
+        for limb in y2.iter_mut() {
+            *limb <<= 1;
+        }
+        PrecomputedF127x4(y, y2, bounds)
+    }
+}
+
+impl Mul<PrecomputedF127x4> for F127x4 {
+    type Output = F127x4;
+    #[inline]
+    fn mul(self, other: PrecomputedF127x4) -> F127x4 {
+        let bounds = self.1.mul_precomputed(other.2);
+        let [x0, x1, x2, x3, x4] = self.0;
+        let [y0, y1, y2, y3, y4] = other.0;
+        let [_, w1, w2, w3, w4] = other.1;
+
+        let m = mul32;
+
+        // As for general multiplication, but the terms with
+        // coefficient 2 use w = 2y.
+        let z0 = m(x0, y0) + m(x1, w4) + m(x4, w1) + m(x2, w3) + m(x3, w2);
+        let z1 = m(x0, y1) + m(x1, y0) + m(x2, y4) + m(x4, y2) + m(x3, w3);
+        let z2 = m(x0, y2) + m(x2, y0) + m(x1, w1) + m(x3, w4) + m(x4, w3);
+        let z3 = m(x0, y3) + m(x3, y0) + m(x1, y2) + m(x2, y1) + m(x4, y4);
+        let z4 = m(x0, y4) + m(x4, y0) + m(x2, y2) + m(x1, w3) + m(x3, w1);
+
+        carry_product([z0, z1, z2, z3, z4], bounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn xs() -> (F127, F127, F127, F127) {
+        (
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+        )
+    }
+
+    #[allow(non_snake_case)]
+    #[test]
+    fn from_into_F127_round_trips() {
+        let xs = xs();
+        let x_vec: F127x4 = xs.into();
+
+        assert_eq!(xs, x_vec.into());
+
+        // p itself is all ones in every limb
+        let p = F127((1 << 127) - 1);
+        let p_vec: (F127, F127, F127, F127) = F127x4::splat(p).into();
+        assert_eq!(p_vec.0, F127::zero());
+    }
+
+    #[test]
+    fn mul_matches_serial() {
+        let xs = xs();
+        let x_vec: F127x4 = xs.into();
+        let y_vec = x_vec.shuffle::<0b00_11_10_01>();
+
+        let zs: (F127, F127, F127, F127) = (x_vec * y_vec).into();
+
+        assert_eq!(zs.0, xs.0 * xs.1);
+        assert_eq!(zs.1, xs.1 * xs.2);
+        assert_eq!(zs.2, xs.2 * xs.3);
+        assert_eq!(zs.3, xs.3 * xs.0);
+    }
+
+    #[test]
+    fn add_negation_is_zero() {
+        let x_vec: F127x4 = xs().into();
+
+        let zs: (F127, F127, F127, F127) = (x_vec + (-x_vec)).into();
+
+        let zero = F127::zero();
+        assert_eq!(zs, (zero, zero, zero, zero));
+    }
+
+    #[test]
+    fn ct_eq_compares_values() {
+        let xs = xs();
+        let x_vec: F127x4 = xs.into();
+
+        // The same values, with different limbs.
+        let y_vec = (x_vec + x_vec) + (-x_vec);
+        assert_eq!(x_vec.ct_eq(y_vec), m64x4::splat(true));
+
+        let z_vec = x_vec.insert(2, xs.0);
+        assert_eq!(x_vec.ct_eq(z_vec), m64x4::new(true, true, false, true));
+
+        assert_eq!(x_vec.is_zero(), m64x4::splat(false));
+        assert_eq!((x_vec + (-x_vec)).is_zero(), m64x4::splat(true));
+
+        // p itself is all ones in every limb, and 8p is -0.
+        let p = F127((1 << 127) - 1);
+        let zero = F127::zero();
+        let w_vec = F127x4::from((p, zero, xs.1, zero)) + (-F127x4::splat(zero));
+        assert_eq!(w_vec.is_zero(), m64x4::new(true, true, false, true));
+        assert_eq!(
+            w_vec.ct_eq(F127x4::splat(zero)),
+            m64x4::new(true, true, false, true)
+        );
+    }
+
+    #[test]
+    fn mul2_and_mul_add2_match_serial() {
+        let xs = xs();
+        let a: F127x4 = xs.into();
+        let b = a.shuffle::<0b00_11_10_01>();
+        let c = a.shuffle::<0b01_00_11_10>();
+        let d = a + a;
+
+        let (ab, cd) = F127x4::mul2((a, b), (c, d));
+        let sum = F127x4::mul_add2((a, b), (c, d));
+
+        let expected_ab: (F127, F127, F127, F127) = (a * b).into();
+        let expected_cd: (F127, F127, F127, F127) = (c * d).into();
+        let expected_sum: (F127, F127, F127, F127) = (a * b + c * d).into();
+
+        assert_eq!(expected_ab, ab.into());
+        assert_eq!(expected_cd, cd.into());
+        assert_eq!(expected_sum, sum.into());
+        assert_eq!(expected_sum.1, xs.1 * xs.2 + xs.3 * (xs.1 + xs.1));
+    }
+
+    #[test]
+    fn mul_accepts_four_bits_of_excess() {
+        let xs = xs();
+        let x_vec: F127x4 = xs.into();
+
+        // Summing 2^4 copies gives limbs of up to 30 bits.
+        let mut y = F127::zero();
+        let mut y_vec = x_vec;
+        for _ in 0..16 {
+            y = y + xs.0;
+        }
+        for _ in 0..15 {
+            y_vec = y_vec + x_vec;
+        }
+
+        let zs: (F127, F127, F127, F127) = (y_vec * y_vec).into();
+
+        assert_eq!(zs.0, y * y);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "overflows a product term")]
+    fn mul_rejects_too_many_unreduced_additions() {
+        let x_vec: F127x4 = xs().into();
+
+        // Summing 2^6 copies gives limbs of up to 32 bits.
+        let mut y_vec = x_vec;
+        for _ in 0..63 {
+            y_vec = y_vec + x_vec;
+        }
+
+        let _ = y_vec * y_vec;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "too large to be negated")]
+    fn neg_rejects_four_bits_of_excess() {
+        let x_vec: F127x4 = xs().into();
+
+        let mut y_vec = x_vec;
+        for _ in 0..15 {
+            y_vec = y_vec + x_vec;
+        }
+
+        let _ = -y_vec;
+    }
+
+    #[test]
+    fn pack_unpack_round_trips() {
+        let xs = xs();
+        let x_vec: F127x4 = xs.into();
+        let z_vec = x_vec * x_vec;
+        let z = (xs.0 * xs.0, xs.1 * xs.1, xs.2 * xs.2, xs.3 * xs.3);
+
+        let packed_xs: (F127, F127, F127, F127) = x_vec.pack().unpack().into();
+        let packed_zs: (F127, F127, F127, F127) = z_vec.pack().unpack().into();
+        let negated: (F127, F127, F127, F127) = (-z_vec).pack().unpack().into();
+
+        assert_eq!(packed_xs, xs);
+        assert_eq!(packed_zs, z);
+        assert_eq!(negated, (-z.0, -z.1, -z.2, -z.3));
+    }
+
+    #[test]
+    fn lane_operations_match_serial() {
+        let xs = xs();
+        let y = F127::from(146218790534899613207529930246486884549u128);
+
+        let x_vec: F127x4 = xs.into();
+
+        let shuffled: (F127, F127, F127, F127) = x_vec.shuffle::<0b10_11_00_01>().into();
+        assert_eq!(shuffled, (xs.1, xs.0, xs.3, xs.2));
+
+        let mask = m64x4::new(false, true, true, false);
+        let blended: (F127, F127, F127, F127) = x_vec.blend(F127x4::splat(y), mask).into();
+        assert_eq!(blended, (xs.0, y, y, xs.3));
+
+        let inserted: (F127, F127, F127, F127) = x_vec.insert(2, y).into();
+        assert_eq!(inserted, (xs.0, xs.1, y, xs.3));
+    }
+
+    #[test]
+    #[cfg(target_feature = "avx512ifma")]
+    fn agrees_with_ifma_backend() {
+        let xs = xs();
+        let y = F127::from(146218790534899613207529930246486884549u128);
+
+        let x_avx2: F127x4 = xs.into();
+        let x_ifma: super::super::prime_field::F127x4 = xs.into();
+
+        let z_avx2: (F127, F127, F127, F127) =
+            (x_avx2 * x_avx2 + (-F127x4::splat(y)) * x_avx2).into();
+        let z_ifma: (F127, F127, F127, F127) =
+            (x_ifma * x_ifma + (-super::super::prime_field::F127x4::splat(y)) * x_ifma).into();
+
+        assert_eq!(z_avx2, z_ifma);
+    }
+
+    #[test]
+    fn precomputed_mul_matches_serial() {
+        let xs = xs();
+        let y = F127::from(146218790534899613207529930246486884549u128);
+
+        let x_vec: F127x4 = xs.into();
+        let y_pre = PrecomputedF127x4::new(F127x4::splat(y) * F127x4::splat(y));
+
+        let zs: (F127, F127, F127, F127) = (x_vec * y_pre).into();
+        assert_eq!(zs, (xs.0 * y * y, xs.1 * y * y, xs.2 * y * y, xs.3 * y * y));
+
+        let zs: (F127, F127, F127, F127) = ((x_vec + x_vec + (-x_vec)) * y_pre).into();
+        assert_eq!(zs, (xs.0 * y * y, xs.1 * y * y, xs.2 * y * y, xs.3 * y * y));
+    }
+}
@@ -0,0 +1,235 @@
+//! Worst-case limb bound tracking for the AVX2 backend in debug builds.
+//!
+//! This plays the same role as `vector::bounds` does for the IFMA
+//! backend: the arithmetic is only correct when the inputs to
+//! `vpmuludq` fit into 32 bits, the sums of product terms fit into 64
+//! bits, and negation does not underflow (see the limb bounds in the
+//! module docs).  In debug builds each vector carries an upper bound on
+//! each of its five limbs (across all lanes), which is propagated
+//! through every operation and checked before it could be violated.
+//!
+//! In release builds `Bounds` is a zero-sized type and all of its
+//! methods are no-ops.
+
+#[cfg(debug_assertions)]
+use super::{MASK25, MASK26};
+
+#[cfg(debug_assertions)]
+const MAX_MUL_INPUT: u64 = (1 << 32) - 1;
+
+/// Upper bounds on the five limbs of an `F127x4`.
+#[cfg(debug_assertions)]
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Bounds([u64; 5]);
+
+/// Upper bounds on the five limbs of an `F127x4` (untracked).
+#[cfg(not(debug_assertions))]
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Bounds;
+
+#[cfg(debug_assertions)]
+impl Bounds {
+    /// Bounds for limbs of at most `limbs[i]`.
+    #[inline]
+    pub(crate) fn new(limbs: [u64; 5]) -> Bounds {
+        Bounds(limbs)
+    }
+
+    /// Bounds for the limbs of a reduced element, each within its
+    /// radix.
+    #[inline]
+    pub(crate) fn reduced() -> Bounds {
+        Bounds([MASK26, MASK25, MASK26, MASK25, MASK25])
+    }
+
+    /// Bounds for a vector whose lanes are taken from either of two
+    /// vectors.
+    #[inline]
+    pub(crate) fn max(self, other: Bounds) -> Bounds {
+        let mut z = self.0;
+        for (zi, yi) in z.iter_mut().zip(other.0.iter()) {
+            *zi = (*zi).max(*yi);
+        }
+        Bounds(z)
+    }
+
+    /// Bounds for the sum of two vectors.
+    #[inline]
+    pub(crate) fn add(self, other: Bounds) -> Bounds {
+        let mut z = self.0;
+        for (zi, yi) in z.iter_mut().zip(other.0.iter()) {
+            *zi = zi
+                .checked_add(*yi)
+                .expect("addition overflows a 64-bit limb");
+        }
+        Bounds(z)
+    }
+
+    /// Bounds for `limbs - self`, checking that the subtraction
+    /// cannot underflow.
+    #[inline]
+    pub(crate) fn sub_from(self, limbs: [u64; 5]) -> Bounds {
+        for (x, limb) in self.0.iter().zip(limbs.iter()) {
+            assert!(x <= limb, "limb too large to be negated");
+        }
+        Bounds(limbs)
+    }
+
+    /// Bounds for the product of two vectors, checking the multiplier
+    /// inputs and the product terms along the way.
+    pub(crate) fn mul(self, other: Bounds) -> Bounds {
+        Bounds::carried(self.products(other))
+    }
+
+    /// Bounds for `x.0 * x.1 + y.0 * y.1`, computed by summing the
+    /// product terms of both products before carrying.
+    pub(crate) fn mul_add2(x: (Bounds, Bounds), y: (Bounds, Bounds)) -> Bounds {
+        let z = x.0.products(x.1);
+        let w = y.0.products(y.1);
+
+        let mut sum = z;
+        for (zi, wi) in sum.iter_mut().zip(w.iter()) {
+            *zi += *wi;
+        }
+        Bounds::carried(sum)
+    }
+
+    /// Bounds for the product of a vector with a precomputed operand
+    /// whose limbs have bounds `other`.
+    ///
+    /// Multiplication by a `PrecomputedF127x4` uses `2 * other` for the
+    /// terms with a coefficient of 2, so the product terms have the same
+    /// bounds as for general multiplication, but the doubled limbs must
+    /// also fit into 32 bits.
+    pub(crate) fn mul_precomputed(self, other: Bounds) -> Bounds {
+        for &limb in &other.0 {
+            assert!(
+                limb << 1 <= MAX_MUL_INPUT,
+                "limb exceeds 32 bits at a multiplier input"
+            );
+        }
+        self.mul(other)
+    }
+
+    /// Bounds on the product terms of two vectors, before carrying,
+    /// checking the multiplier inputs.
+    ///
+    /// This mirrors the operation schedule of `products`.
+    fn products(self, other: Bounds) -> [u128; 5] {
+        for (&x, &y) in self.0.iter().zip(other.0.iter()) {
+            assert!(
+                x <= MAX_MUL_INPUT && y <= MAX_MUL_INPUT,
+                "limb exceeds 32 bits at a multiplier input"
+            );
+        }
+
+        let [x0, x1, x2, x3, x4] = self.0;
+        let [y0, y1, y2, y3, y4] = other.0;
+        let m = |x: u64, y: u64| (x as u128) * (y as u128);
+
+        // Every partial sum is bounded by the final z_k, so it's enough
+        // to check those.
+        [
+            m(x0, y0) + ((m(x1, y4) + m(x4, y1) + m(x2, y3) + m(x3, y2)) << 1),
+            m(x0, y1) + m(x1, y0) + m(x2, y4) + m(x4, y2) + (m(x3, y3) << 1),
+            m(x0, y2) + m(x2, y0) + ((m(x1, y1) + m(x3, y4) + m(x4, y3)) << 1),
+            m(x0, y3) + m(x3, y0) + m(x1, y2) + m(x2, y1) + m(x4, y4),
+            m(x0, y4) + m(x4, y0) + m(x2, y2) + ((m(x1, y3) + m(x3, y1)) << 1),
+        ]
+    }
+
+    /// Bounds for the result of `carry_product`, given bounds on the
+    /// product terms.
+    fn carried(z: [u128; 5]) -> Bounds {
+        for zi in &z {
+            assert!(*zi >> 64 == 0, "multiplication overflows a product term");
+        }
+        let [mut z0, mut z1, mut z2, mut z3, mut z4] = z.map(|zi| zi as u64);
+        let carry = |x: u64, c: u64| x.checked_add(c).expect("carry overflows a 64-bit limb");
+
+        // This mirrors the two interleaved carry chains of
+        // `carry_product`.
+        z1 = carry(z1, z0 >> 26);
+        z0 = z0.min(MASK26);
+        z4 = carry(z4, z3 >> 25);
+        z3 = z3.min(MASK25);
+
+        z2 = carry(z2, z1 >> 25);
+        z1 = z1.min(MASK25);
+        z0 = carry(z0, z4 >> 25);
+        z4 = z4.min(MASK25);
+
+        z3 = carry(z3, z2 >> 26);
+        z2 = z2.min(MASK26);
+        z1 = carry(z1, z0 >> 26);
+        z0 = z0.min(MASK26);
+
+        z4 = carry(z4, z3 >> 25);
+        z3 = z3.min(MASK25);
+
+        Bounds([z0, z1, z2, z3, z4])
+    }
+
+    /// Checks that none of the carries in `F127x4::reduce` can overflow
+    /// a 64-bit limb for a vector with these bounds.
+    #[inline]
+    pub(crate) fn check_reducible(self) {
+        let [b0, b1, b2, b3, b4] = self.0;
+        let carry = |x: u64, c: u64| x.checked_add(c).expect("reduction overflows a 64-bit limb");
+
+        // This mirrors the carry chain of `F127x4::reduce`, where each
+        // limb is masked after its carry has been propagated.
+        let l1 = carry(b1, b0 >> 26);
+        let l2 = carry(b2, l1 >> 25);
+        let l3 = carry(b3, l2 >> 26);
+        let l4 = carry(b4, l3 >> 25);
+        let l0 = carry(b0.min(MASK26), l4 >> 25);
+        carry(l1.min(MASK25), l0 >> 26);
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl Bounds {
+    #[inline(always)]
+    pub(crate) fn new(_limbs: [u64; 5]) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn reduced() -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn max(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_from(self, _limbs: [u64; 5]) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_add2(_x: (Bounds, Bounds), _y: (Bounds, Bounds)) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_precomputed(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn check_reducible(self) {}
+}
@@ -0,0 +1,252 @@
+//! Worst-case limb bound tracking for debug builds.
+//!
+//! The arithmetic in `F127x4` and `F127x8` is only correct when the limbs of the
+//! inputs to a multiplication fit into 52 bits and the IFMA
+//! accumulators do not overflow 64 bits (see the notes on the choice
+//! of bit-excess \\(b\\) in the module docs).  Nothing in the types
+//! enforces this, so in debug builds each vector carries an upper
+//! bound on each of its limbs (across all lanes), which is propagated
+//! through every operation and checked before it could be violated.
+//!
+//! In release builds `Bounds` is a zero-sized type and all of its
+//! methods are no-ops.
+
+#[cfg(debug_assertions)]
+const MAX_IFMA_INPUT: u64 = (1 << 52) - 1;
+
+/// Upper bounds on the three limbs of an `F127x4` or `F127x8`.
+#[cfg(debug_assertions)]
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Bounds([u64; 3]);
+
+/// Upper bounds on the three limbs of an `F127x4` or `F127x8`
+/// (untracked).
+#[cfg(not(debug_assertions))]
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct Bounds;
+
+#[cfg(debug_assertions)]
+impl Bounds {
+    /// Bounds for limbs of at most `limbs[i]`.
+    #[inline]
+    pub(crate) fn new(limbs: [u64; 3]) -> Bounds {
+        Bounds(limbs)
+    }
+
+    /// Bounds for the limbs of a freshly converted element.
+    #[inline]
+    pub(crate) fn reduced() -> Bounds {
+        Bounds([(1 << 43) - 1, (1 << 43) - 1, (1 << 41) - 1])
+    }
+
+    /// Bounds for a vector whose lanes are taken from either of two
+    /// vectors.
+    #[inline]
+    pub(crate) fn max(self, other: Bounds) -> Bounds {
+        Bounds([
+            self.0[0].max(other.0[0]),
+            self.0[1].max(other.0[1]),
+            self.0[2].max(other.0[2]),
+        ])
+    }
+
+    /// Bounds for the sum of two vectors.
+    #[inline]
+    pub(crate) fn add(self, other: Bounds) -> Bounds {
+        let mut z = self.0;
+        for (zi, yi) in z.iter_mut().zip(other.0.iter()) {
+            *zi = zi
+                .checked_add(*yi)
+                .expect("addition overflows a 64-bit limb");
+        }
+        Bounds(z)
+    }
+
+    /// Bounds for `limbs - self`, checking that the subtraction
+    /// cannot underflow.
+    #[inline]
+    pub(crate) fn sub_from(self, limbs: [u64; 3]) -> Bounds {
+        for (x, limb) in self.0.iter().zip(limbs.iter()) {
+            assert!(x <= limb, "limb too large to be negated");
+        }
+        Bounds(limbs)
+    }
+
+    /// Bounds for the product of two vectors, checking the IFMA
+    /// inputs and accumulators along the way.
+    pub(crate) fn mul(self, other: Bounds) -> Bounds {
+        Bounds::carried(self.products(other))
+    }
+
+    /// Bounds for `x.0 * x.1 + y.0 * y.1`, computed by summing the
+    /// accumulators of both products before carrying.
+    pub(crate) fn mul_add2(x: (Bounds, Bounds), y: (Bounds, Bounds)) -> Bounds {
+        let z = x.0.products(x.1);
+        let w = y.0.products(y.1);
+
+        Bounds::carried([z[0] + w[0], z[1] + w[1], z[2] + w[2]])
+    }
+
+    /// Bounds on the accumulators of the product of two vectors,
+    /// before carrying, checking the IFMA inputs.
+    ///
+    /// This mirrors the operation schedule of `F127x4::mul`.
+    fn products(self, other: Bounds) -> [u128; 3] {
+        for i in 0..3 {
+            assert!(
+                self.0[i] <= MAX_IFMA_INPUT && other.0[i] <= MAX_IFMA_INPUT,
+                "limb exceeds 52 bits at an IFMA input"
+            );
+        }
+
+        let (x0, x1, x2) = (self.0[0], self.0[1], self.0[2]);
+        let (y0, y1, y2) = (other.0[0], other.0[1], other.0[2]);
+
+        let lo = |x: u64, y: u64| ((x as u128) * (y as u128)).min(MAX_IFMA_INPUT as u128);
+        let hi = |x: u64, y: u64| ((x as u128) * (y as u128)) >> 52;
+
+        // Every intermediate value in the accumulation chains is
+        // bounded by the final z_i, so it's enough to check those.
+        let z0 = (hi(x2, y0) << 11)
+            + lo(x0, y0)
+            + ((lo(x2, y1) + lo(x1, y2)) << 2)
+            + ((hi(x1, y1) + hi(x0, y2)) << 11);
+        let z1 = (((hi(x0, y0) << 7) + lo(x2, y2)) << 2)
+            + ((hi(x2, y1) + hi(x1, y2)) << 11)
+            + (lo(x1, y0) + lo(x0, y1));
+        let z2 = (hi(x2, y2) << 11)
+            + lo(x0, y2)
+            + ((hi(x1, y0) + hi(x0, y1)) << 9)
+            + (lo(x2, y0) + lo(x1, y1));
+
+        [z0, z1, z2]
+    }
+
+    /// Bounds for the product of a vector with a precomputed operand
+    /// whose limbs have bounds `other`, checking the IFMA inputs and
+    /// accumulators along the way.
+    ///
+    /// This mirrors the operation schedule of multiplication by a
+    /// `PrecomputedF127x4`, which uses `4 * other` for the terms that
+    /// wrap around.
+    pub(crate) fn mul_precomputed(self, other: Bounds) -> Bounds {
+        let (x0, x1, x2) = (self.0[0], self.0[1], self.0[2]);
+        let (y0, y1, y2) = (other.0[0], other.0[1], other.0[2]);
+        let (w1, w2) = (y1 << 2, y2 << 2);
+
+        for &limb in &[x0, x1, x2, y0, w1, w2] {
+            assert!(
+                limb <= MAX_IFMA_INPUT,
+                "limb exceeds 52 bits at an IFMA input"
+            );
+        }
+
+        let lo = |x: u64, y: u64| ((x as u128) * (y as u128)).min(MAX_IFMA_INPUT as u128);
+        let hi = |x: u64, y: u64| ((x as u128) * (y as u128)) >> 52;
+
+        let z0 =
+            lo(x0, y0) + lo(x2, w1) + lo(x1, w2) + ((hi(x2, y0) + hi(x1, y1) + hi(x0, y2)) << 11);
+        let z1 =
+            lo(x1, y0) + lo(x0, y1) + lo(x2, w2) + ((hi(x0, y0) + hi(x2, w1) + hi(x1, w2)) << 9);
+        let z2 =
+            lo(x2, y0) + lo(x1, y1) + lo(x0, y2) + ((hi(x1, y0) + hi(x0, y1) + hi(x2, w2)) << 9);
+
+        Bounds::carried([z0, z1, z2])
+    }
+
+    /// Bounds for the result of the carry step at the end of a
+    /// multiplication, given bounds on the accumulators.
+    fn carried(z: [u128; 3]) -> Bounds {
+        for zi in &z {
+            assert!(*zi >> 64 == 0, "multiplication overflows an accumulator");
+        }
+
+        let mask = (1u128 << 43) - 1;
+        let carried = |z: u128, c: u128| (z.min(mask) + c) as u64;
+
+        Bounds([
+            carried(z[0], (z[2] >> 43) << 2),
+            carried(z[1], z[0] >> 43),
+            carried(z[2], z[1] >> 43),
+        ])
+    }
+
+    /// Checks that none of the carries in `F127x4::reduce` can overflow
+    /// a 64-bit limb for a vector with these bounds.
+    #[inline]
+    pub(crate) fn check_reducible(self) {
+        let (b0, b1, b2) = (self.0[0], self.0[1], self.0[2]);
+        let mask43 = (1 << 43) - 1;
+        let carry = |x: u64, c: u64| x.checked_add(c).expect("reduction overflows a 64-bit limb");
+
+        // This mirrors the carry chain of `F127x4::reduce`, where each
+        // limb is masked after its carry has been propagated.
+        let l1 = carry(b1, b0 >> 43);
+        let l2 = carry(b2, l1 >> 43);
+        let l0 = carry(b0.min(mask43), l2 >> 41);
+        carry(l1.min(mask43), l0 >> 43);
+    }
+
+    /// Checks that a vector with these bounds can be converted to
+    /// serial field elements.
+    #[inline]
+    pub(crate) fn check_convertible(self) {
+        // The conversion carries the top bits of the high limb into the
+        // low limb, then requires the result to be less than 2p = 2^128 - 2.
+        let (b0, b1, b2) = (self.0[0], self.0[1], self.0[2]);
+        let l0 = b0
+            .checked_add(b2 >> 41)
+            .expect("limbs too large to convert to F127");
+        let x = (l0 as u128) + ((b1 as u128) << 43) + ((b2.min((1 << 41) - 1) as u128) << 86);
+        assert!(x < u128::MAX - 1, "limbs too large to convert to F127");
+    }
+}
+
+#[cfg(not(debug_assertions))]
+impl Bounds {
+    #[inline(always)]
+    pub(crate) fn new(_limbs: [u64; 3]) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn reduced() -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn max(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn add(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_from(self, _limbs: [u64; 3]) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_add2(_x: (Bounds, Bounds), _y: (Bounds, Bounds)) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_precomputed(self, _other: Bounds) -> Bounds {
+        Bounds
+    }
+
+    #[inline(always)]
+    pub(crate) fn check_reducible(self) {}
+
+    #[inline(always)]
+    pub(crate) fn check_convertible(self) {}
+}
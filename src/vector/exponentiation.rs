@@ -0,0 +1,172 @@
+//! Fixed exponentiation chains: inversion and square roots.
+//!
+//! These are computed lane-wise directly on the vector
+//! representation, so that (for instance) batch normalization does
+//! not need to convert to serial elements and back.
+
+use crate::simd::m64x4;
+
+/// Implements squaring, inversion, and square roots for a vector of
+/// prime field elements in terms of its multiplication.
+macro_rules! impl_exponentiation {
+    ($F:ty) => {
+        impl $F {
+            /// Compute `self * self`.
+            #[inline]
+            pub fn square(self) -> $F {
+                self * self
+            }
+
+            /// Compute \\(x^{2^k}\\) by repeated squaring.
+            #[inline]
+            pub fn pow2k(self, k: u32) -> $F {
+                let mut x = self;
+                for _ in 0..k {
+                    x = x.square();
+                }
+                x
+            }
+
+            /// Compute the multiplicative inverse of each lane of `self`,
+            /// or zero in lanes where `self` is zero.
+            ///
+            /// This computes \\(x^{p-2} = x^{2^{127} - 3}\\) using a fixed
+            /// chain of 126 squarings and 10 multiplications.
+            pub fn invert(self) -> $F {
+                // Write a_k = x^(2^k - 1).
+                let a1 = self;
+                let a2 = a1.square() * a1;
+                let a4 = a2.pow2k(2) * a2;
+                let a5 = a4.square() * a1;
+                let a10 = a5.pow2k(5) * a5;
+                let a20 = a10.pow2k(10) * a10;
+                let a25 = a20.pow2k(5) * a5;
+                let a50 = a25.pow2k(25) * a25;
+                let a100 = a50.pow2k(50) * a50;
+                let a125 = a100.pow2k(25) * a25;
+
+                // (2^125 - 1) * 2^2 + 1 = 2^127 - 3
+                a125.pow2k(2) * a1
+            }
+
+            /// Compute a square root of each lane of `self`, returning
+            /// `(is_square, root)`.
+            ///
+            /// Since \\(p \equiv 3 \pmod 4\\), the candidate root is
+            /// \\(x^{(p+1)/4} = x^{2^{125}}\\).  In lanes where `self` is
+            /// not a square, this is instead a square root of `-self`
+            /// (which is then a square, since \\(-1\\) is not), so those
+            /// lanes are cleared in the `is_square` mask.
+            pub fn sqrt(self) -> (m64x4, $F) {
+                let r = self.pow2k(125);
+                (r.square().ct_eq(self), r)
+            }
+        }
+    };
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl_exponentiation!(super::prime_field::F127x4);
+impl_exponentiation!(super::avx2::F127x4);
+
+#[cfg(test)]
+mod tests {
+    use super::super::{avx2, F127x4};
+    use crate::serial::F127;
+    use crate::simd::m64x4;
+
+    fn xs() -> (F127, F127, F127, F127) {
+        (
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+        )
+    }
+
+    #[test]
+    fn invert_gives_inverse() {
+        let x_vec: F127x4 = xs().into();
+
+        let z: (F127, F127, F127, F127) = (x_vec * x_vec.invert()).into();
+
+        let one = F127::from(1);
+        assert_eq!(z, (one, one, one, one));
+    }
+
+    #[test]
+    fn invert_zero_is_zero() {
+        let zero = F127::zero();
+        let x_vec = F127x4::splat(zero).insert(1, xs().1);
+
+        let z: (F127, F127, F127, F127) = x_vec.invert().into();
+
+        assert_eq!(z.0, zero);
+        assert_ne!(z.1, zero);
+        assert_eq!(z.2, zero);
+    }
+
+    #[test]
+    fn sqrt_of_square() {
+        let x_vec: F127x4 = xs().into();
+        let x2 = x_vec.square();
+
+        let (is_square, y) = x2.sqrt();
+        let y: (F127, F127, F127, F127) = y.square().into();
+        let x2: (F127, F127, F127, F127) = x2.into();
+
+        assert!(is_square.all());
+        assert_eq!(y, x2);
+    }
+
+    #[test]
+    fn sqrt_of_nonsquare() {
+        let x_vec: F127x4 = xs().into();
+        let x2 = x_vec.square();
+
+        // -x^2 is not a square, so we get a square root of x^2
+        let (is_square, y) = (-x2).sqrt();
+        let y: (F127, F127, F127, F127) = y.square().into();
+        let x2: (F127, F127, F127, F127) = x2.into();
+
+        assert!(!is_square.any());
+        assert_eq!(y, x2);
+    }
+
+    #[test]
+    fn sqrt_flags_each_lane() {
+        let x_vec: F127x4 = xs().into();
+        let x2 = x_vec.square();
+        let mixed = x2.blend(-x2, m64x4::new(false, true, false, true));
+
+        let (is_square, _) = mixed.sqrt();
+        assert_eq!(is_square.bitmask(), 0b0101);
+
+        let (is_square, _) = F127x4::splat(F127::zero()).sqrt();
+        assert!(is_square.all());
+    }
+
+    #[test]
+    fn avx2_sqrt_flags_each_lane() {
+        let x_vec: avx2::F127x4 = xs().into();
+        let x2 = x_vec.square();
+        let mixed = x2.blend(-x2.reduce(), m64x4::new(false, true, false, true));
+
+        let (is_square, y) = mixed.sqrt();
+        let y: (F127, F127, F127, F127) = y.square().into();
+        let x2: (F127, F127, F127, F127) = x2.into();
+
+        assert_eq!(is_square.bitmask(), 0b0101);
+        assert_eq!(y, x2);
+    }
+
+    #[test]
+    fn avx2_invert_gives_inverse() {
+        let x_vec: avx2::F127x4 = xs().into();
+
+        let z: (F127, F127, F127, F127) = (x_vec * x_vec.invert()).into();
+
+        let one = F127::from(1);
+        assert_eq!(z, (one, one, one, one));
+    }
+}
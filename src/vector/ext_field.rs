@@ -1,12 +1,47 @@
 //! Vectorized arithmetic for the extension field
 
-use super::F127x4;
+use crate::simd::m64x4;
+
+#[cfg(target_feature = "avx512ifma")]
+use super::F127x8;
+use super::{F127x4, PackedF127x4, PrecomputedF127x4};
 use crate::serial::{ExtF127, F127};
 
 /// A vector of four elements of the extension field.
 #[derive(Copy, Clone)]
 pub struct ExtF127x4(F127x4, F127x4);
 
+/// A compact storage form of an `ExtF127x4`.
+///
+/// See `PackedF127x4`.
+#[derive(Copy, Clone)]
+pub struct PackedExtF127x4(PackedF127x4, PackedF127x4);
+
+/// A vector of four elements of the extension field, prepared for use
+/// as a fixed multiplier.
+///
+/// For a multiplier \\(c + di\\), this stores precomputed forms of
+/// \\(c\\), \\(d\\), and \\(c - d\\), so that all three prime field
+/// multiplications use a `PrecomputedF127x4`, and the subtraction
+/// \\(c - d\\) is done only once.
+#[derive(Copy, Clone)]
+pub struct PrecomputedExtF127x4(PrecomputedF127x4, PrecomputedF127x4, PrecomputedF127x4);
+
+/// A vector of two elements of the extension field.
+///
+/// Where `ExtF127x4` stores real and imaginary parts in separate prime
+/// field vectors, this interleaves them as \\((a_0, b_0, a_1, b_1)\\)
+/// in a single `F127x4`.  This halves the width, but lets one or two
+/// latency-bound computations (such as a single inversion chain) use
+/// all four lanes of the prime field arithmetic.
+#[derive(Copy, Clone)]
+pub struct ExtF127x2(F127x4);
+
+/// A vector of eight elements of the extension field.
+#[cfg(target_feature = "avx512ifma")]
+#[derive(Copy, Clone)]
+pub struct ExtF127x8(F127x8, F127x8);
+
 impl From<(ExtF127, ExtF127, ExtF127, ExtF127)> for ExtF127x4 {
     fn from(x: (ExtF127, ExtF127, ExtF127, ExtF127)) -> ExtF127x4 {
         ExtF127x4(
@@ -16,10 +51,10 @@ impl From<(ExtF127, ExtF127, ExtF127, ExtF127)> for ExtF127x4 {
     }
 }
 
-impl Into<(ExtF127, ExtF127, ExtF127, ExtF127)> for ExtF127x4 {
-    fn into(self) -> (ExtF127, ExtF127, ExtF127, ExtF127) {
-        let xs: (F127, F127, F127, F127) = self.0.into();
-        let ys: (F127, F127, F127, F127) = self.1.into();
+impl From<ExtF127x4> for (ExtF127, ExtF127, ExtF127, ExtF127) {
+    fn from(x: ExtF127x4) -> (ExtF127, ExtF127, ExtF127, ExtF127) {
+        let xs: (F127, F127, F127, F127) = x.0.into();
+        let ys: (F127, F127, F127, F127) = x.1.into();
 
         (
             ExtF127(xs.0, ys.0),
@@ -30,27 +65,289 @@ impl Into<(ExtF127, ExtF127, ExtF127, ExtF127)> for ExtF127x4 {
     }
 }
 
+impl ExtF127x4 {
+    /// Construct a vector with `x` in every lane.
+    #[inline]
+    pub fn splat(x: ExtF127) -> ExtF127x4 {
+        ExtF127x4(F127x4::splat(x.0), F127x4::splat(x.1))
+    }
+
+    /// Extract the element in the given `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub fn extract(self, lane: usize) -> ExtF127 {
+        ExtF127(self.0.extract(lane), self.1.extract(lane))
+    }
+
+    /// Return a copy of `self` with the element in the given `lane`
+    /// replaced by `x`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub fn insert(self, lane: usize, x: ExtF127) -> ExtF127x4 {
+        ExtF127x4(self.0.insert(lane, x.0), self.1.insert(lane, x.1))
+    }
+
+    /// Permute the lanes of `self` according to `PATTERN`.
+    ///
+    /// See `F127x4::shuffle` for the encoding of `PATTERN`.
+    #[inline]
+    pub fn shuffle<const PATTERN: i32>(self) -> ExtF127x4 {
+        ExtF127x4(self.0.shuffle::<PATTERN>(), self.1.shuffle::<PATTERN>())
+    }
+
+    /// Return a vector whose lanes are taken from `other` where `mask`
+    /// is set, and from `self` where it is not.
+    #[inline]
+    pub fn blend(self, other: ExtF127x4, mask: m64x4) -> ExtF127x4 {
+        ExtF127x4(self.0.blend(other.0, mask), self.1.blend(other.1, mask))
+    }
+
+    /// Return a mask of the lanes where `self` and `other` are equal,
+    /// in constant time.
+    ///
+    /// See `F127x4::ct_eq`.
+    #[inline]
+    pub fn ct_eq(self, other: ExtF127x4) -> m64x4 {
+        self.0.ct_eq(other.0) & self.1.ct_eq(other.1)
+    }
+
+    /// Return a mask of the lanes where `self` is zero, in constant
+    /// time.
+    #[inline]
+    pub fn is_zero(self) -> m64x4 {
+        self.0.is_zero() & self.1.is_zero()
+    }
+
+    /// Compute the multiplicative inverse of each lane of `self`, or
+    /// zero in lanes where `self` is zero.
+    ///
+    /// This uses \\((a + bi)^{-1} = (a - bi)/(a^2 + b^2)\\), so it
+    /// costs one prime field inversion.
+    pub fn invert(self) -> ExtF127x4 {
+        let (a, b) = (self.0, self.1);
+
+        let norm_inv = (a.square() + b.square()).invert();
+
+        ExtF127x4(a * norm_inv, -(b * norm_inv))
+    }
+
+    /// Weakly reduce both components of `self`.
+    ///
+    /// See `F127x4::reduce`.
+    #[inline]
+    pub fn reduce(self) -> ExtF127x4 {
+        ExtF127x4(self.0.reduce(), self.1.reduce())
+    }
+
+    /// Convert `self` to its compact storage form.
+    #[inline]
+    pub fn pack(self) -> PackedExtF127x4 {
+        PackedExtF127x4(self.0.pack(), self.1.pack())
+    }
+}
+
+impl PackedExtF127x4 {
+    /// Convert `self` to the form used for arithmetic.
+    #[inline]
+    pub fn unpack(self) -> ExtF127x4 {
+        ExtF127x4(self.0.unpack(), self.1.unpack())
+    }
+}
+
+impl From<(ExtF127, ExtF127)> for ExtF127x2 {
+    fn from(x: (ExtF127, ExtF127)) -> ExtF127x2 {
+        ExtF127x2(((x.0).0, (x.0).1, (x.1).0, (x.1).1).into())
+    }
+}
+
+impl From<ExtF127x2> for (ExtF127, ExtF127) {
+    fn from(x: ExtF127x2) -> (ExtF127, ExtF127) {
+        let xs: (F127, F127, F127, F127) = x.0.into();
+
+        (ExtF127(xs.0, xs.1), ExtF127(xs.2, xs.3))
+    }
+}
+
+/// Swap the real and imaginary lanes of each element.
+const SWAP_PARTS: i32 = 0b10_11_00_01;
+
+impl ExtF127x2 {
+    /// Construct a vector with `x` in both lanes.
+    #[inline]
+    pub fn splat(x: ExtF127) -> ExtF127x2 {
+        ExtF127x2::from((x, x))
+    }
+
+    /// Extract the element in the given `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 2.
+    #[inline]
+    pub fn extract(self, lane: usize) -> ExtF127 {
+        assert!(lane < 2, "lane index out of range");
+        ExtF127(self.0.extract(2 * lane), self.0.extract(2 * lane + 1))
+    }
+
+    /// Compute the complex conjugate of each lane of `self`.
+    #[inline]
+    pub fn conjugate(self) -> ExtF127x2 {
+        let imaginary = m64x4::new(false, true, false, true);
+        ExtF127x2(self.0.blend(-self.0, imaginary))
+    }
+
+    /// Compute the multiplicative inverse of each lane of `self`, or
+    /// zero in lanes where `self` is zero.
+    ///
+    /// As for `ExtF127x4::invert`, this divides the conjugate by the
+    /// norm; the norm is broadcast to both parts of each element, so
+    /// the prime field inversion runs in all four lanes at once.
+    pub fn invert(self) -> ExtF127x2 {
+        let squares = self.0 * self.0;
+        let norm = squares + squares.shuffle::<SWAP_PARTS>();
+
+        ExtF127x2(self.conjugate().0 * norm.invert())
+    }
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl From<[ExtF127; 8]> for ExtF127x8 {
+    fn from(x: [ExtF127; 8]) -> ExtF127x8 {
+        let mut re = [F127::zero(); 8];
+        let mut im = [F127::zero(); 8];
+        for (j, xj) in x.iter().enumerate() {
+            re[j] = xj.0;
+            im[j] = xj.1;
+        }
+        ExtF127x8(re.into(), im.into())
+    }
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl From<ExtF127x8> for [ExtF127; 8] {
+    fn from(x: ExtF127x8) -> [ExtF127; 8] {
+        let re: [F127; 8] = x.0.into();
+        let im: [F127; 8] = x.1.into();
+
+        let mut z = [ExtF127(F127::zero(), F127::zero()); 8];
+        for (j, zj) in z.iter_mut().enumerate() {
+            *zj = ExtF127(re[j], im[j]);
+        }
+        z
+    }
+}
+
 use core::ops::{Add, Mul, Neg};
 
-impl Add<ExtF127x4> for ExtF127x4 {
-    type Output = ExtF127x4;
+/// Implements negation, addition, and multiplication for a vector of
+/// extension field elements, in terms of its prime field vectors.
+macro_rules! impl_arithmetic {
+    ($E:ident, $F:ident) => {
+        impl Neg for $E {
+            type Output = $E;
+            #[inline]
+            fn neg(self) -> $E {
+                $E(-self.0, -self.1)
+            }
+        }
+
+        impl Add<$E> for $E {
+            type Output = $E;
+            #[inline]
+            fn add(self, other: $E) -> $E {
+                $E(self.0 + other.0, self.1 + other.1)
+            }
+        }
+
+        impl Mul<$E> for $E {
+            type Output = $E;
+            #[inline]
+            fn mul(self, other: $E) -> $E {
+                let (a, b) = (self.0, self.1);
+                let (c, d) = (other.0, other.1);
+
+                let (ac, bd) = $F::mul2((a, c), (b, d));
+
+                $E(ac + (-bd), (b + (-a)) * (c + (-d)) + ac + bd)
+            }
+        }
+    };
+}
+
+impl_arithmetic!(ExtF127x4, F127x4);
+#[cfg(target_feature = "avx512ifma")]
+impl_arithmetic!(ExtF127x8, F127x8);
+
+impl Neg for ExtF127x2 {
+    type Output = ExtF127x2;
     #[inline]
-    fn add(self, other: ExtF127x4) -> ExtF127x4 {
-        ExtF127x4(self.0 + other.0, self.1 + other.1)
+    fn neg(self) -> ExtF127x2 {
+        ExtF127x2(-self.0)
+    }
+}
+
+impl Add<ExtF127x2> for ExtF127x2 {
+    type Output = ExtF127x2;
+    #[inline]
+    fn add(self, other: ExtF127x2) -> ExtF127x2 {
+        ExtF127x2(self.0 + other.0)
+    }
+}
+
+impl Mul<ExtF127x2> for ExtF127x2 {
+    type Output = ExtF127x2;
+    /// Multiply using two independent prime field multiplications,
+    /// \\((ac, bd, \ldots)\\) and \\((bc, ad, \ldots)\\), then
+    /// shuffle the products into place.
+    #[inline]
+    fn mul(self, other: ExtF127x2) -> ExtF127x2 {
+        let imaginary = m64x4::new(false, true, false, true);
+        let real = m64x4::new(true, false, true, false);
+
+        let x = self.0;
+        let y = other.0;
+
+        // (ac, bd), (bc, ad)
+        let (t0, t1) = F127x4::mul2((x, y), (x.shuffle::<SWAP_PARTS>(), y));
+
+        // (ac, bc), (bd, ad)
+        let u = t0.blend(t1.shuffle::<SWAP_PARTS>(), imaginary);
+        let v = t0.shuffle::<SWAP_PARTS>().blend(t1, imaginary);
+
+        // (ac - bd, bc + ad)
+        ExtF127x2(u + v.blend(-v, real))
+    }
+}
+
+impl PrecomputedExtF127x4 {
+    /// Prepare `y` for use as a fixed multiplier.
+    pub fn new(y: ExtF127x4) -> PrecomputedExtF127x4 {
+        let (c, d) = (y.0, y.1);
+
+        PrecomputedExtF127x4(
+            PrecomputedF127x4::new(c),
+            PrecomputedF127x4::new(d),
+            PrecomputedF127x4::new(c + (-d)),
+        )
     }
 }
 
-impl Mul<ExtF127x4> for ExtF127x4 {
+impl Mul<PrecomputedExtF127x4> for ExtF127x4 {
     type Output = ExtF127x4;
     #[inline]
-    fn mul(self, other: ExtF127x4) -> ExtF127x4 {
+    fn mul(self, other: PrecomputedExtF127x4) -> ExtF127x4 {
         let (a, b) = (self.0, self.1);
-        let (c, d) = (other.0, other.1);
 
-        let ac = a * c;
-        let bd = b * d;
+        let ac = a * other.0;
+        let bd = b * other.1;
 
-        ExtF127x4(ac + (-bd), (b + (-a)) * (c + (-d)) + ac + bd)
+        ExtF127x4(ac + (-bd), (b + (-a)) * other.2 + ac + bd)
     }
 }
 
@@ -90,4 +387,206 @@ mod tests {
         assert_eq!(zs.2, xs.2 * xs.2);
         assert_eq!(zs.3, xs.3 * xs.3);
     }
+
+    #[test]
+    #[cfg(target_feature = "avx512ifma")]
+    fn x8_mul_matches_serial() {
+        let a = 43654918112560223727172090912658261884u128;
+        let b = 38188712660835962328561942614081743514u128;
+        let c = 61331686004747624160469066397670963925u128;
+
+        let xs = [
+            ExtF127::from((a, b)),
+            ExtF127::from((b, c)),
+            ExtF127::from((b, a)),
+            ExtF127::from((a, c)),
+            ExtF127::from((c, a)),
+            ExtF127::from((c, b)),
+            ExtF127::from((a, a)),
+            ExtF127::from((c, c)),
+        ];
+
+        let x_vec: ExtF127x8 = xs.into();
+
+        let zs: [ExtF127; 8] = (x_vec * x_vec + x_vec).into();
+
+        for j in 0..8 {
+            assert_eq!(zs[j], xs[j] * xs[j] + xs[j]);
+        }
+    }
+
+    #[test]
+    fn pack_unpack_round_trips() {
+        let xs = (
+            ExtF127::from((
+                43654918112560223727172090912658261884u128,
+                38188712660835962328561942614081743514u128,
+            )),
+            ExtF127::from((
+                38188712660835962328561942614081743514u128,
+                61331686004747624160469066397670963925u128,
+            )),
+            ExtF127::from((
+                38188712660835962328561942614081743514u128,
+                43654918112560223727172090912658261884u128,
+            )),
+            ExtF127::from((
+                43654918112560223727172090912658261884u128,
+                61331686004747624160469066397670963925u128,
+            )),
+        );
+
+        let x_vec: ExtF127x4 = xs.into();
+        let z_vec = x_vec * x_vec;
+
+        let packed_xs: (ExtF127, ExtF127, ExtF127, ExtF127) = x_vec.pack().unpack().into();
+        let packed_zs: (ExtF127, ExtF127, ExtF127, ExtF127) = z_vec.pack().unpack().into();
+        let zs: (ExtF127, ExtF127, ExtF127, ExtF127) = z_vec.into();
+
+        assert_eq!(packed_xs, xs);
+        assert_eq!(packed_zs, zs);
+    }
+
+    #[test]
+    fn lane_operations_match_serial() {
+        let a = 43654918112560223727172090912658261884u128;
+        let b = 38188712660835962328561942614081743514u128;
+        let c = 61331686004747624160469066397670963925u128;
+
+        let xs = (
+            ExtF127::from((a, b)),
+            ExtF127::from((b, c)),
+            ExtF127::from((c, a)),
+            ExtF127::from((a, c)),
+        );
+        let y = ExtF127::from((c, b));
+
+        let x_vec: ExtF127x4 = xs.into();
+
+        let shuffled: (ExtF127, ExtF127, ExtF127, ExtF127) =
+            x_vec.shuffle::<0b00_01_10_11>().into();
+        assert_eq!(shuffled, (xs.3, xs.2, xs.1, xs.0));
+
+        let mask = m64x4::new(true, false, false, true);
+        let blended: (ExtF127, ExtF127, ExtF127, ExtF127) =
+            x_vec.blend(ExtF127x4::splat(y), mask).into();
+        assert_eq!(blended, (y, xs.1, xs.2, y));
+
+        let inserted = x_vec.insert(1, y);
+        assert_eq!(inserted.extract(0), xs.0);
+        assert_eq!(inserted.extract(1), y);
+        assert_eq!(inserted.extract(2), xs.2);
+        assert_eq!(inserted.extract(3), xs.3);
+    }
+
+    #[test]
+    fn ct_eq_compares_values() {
+        let a = 43654918112560223727172090912658261884u128;
+        let b = 38188712660835962328561942614081743514u128;
+
+        let xs = (
+            ExtF127::from((a, b)),
+            ExtF127::from((b, a)),
+            ExtF127::from((a, 0)),
+            ExtF127::from((0, 0)),
+        );
+        let ys = (xs.0, ExtF127::from((b, b)), ExtF127::from((0, 0)), xs.3);
+
+        let x_vec: ExtF127x4 = xs.into();
+        let y_vec: ExtF127x4 = ys.into();
+
+        let mask = m64x4::new(true, false, false, true);
+        assert_eq!(x_vec.ct_eq(y_vec + y_vec + (-y_vec)), mask);
+        assert_eq!(x_vec.is_zero(), m64x4::new(false, false, false, true));
+        assert_eq!(y_vec.is_zero(), m64x4::new(false, false, true, true));
+    }
+
+    #[test]
+    fn invert_gives_inverse() {
+        let a = 43654918112560223727172090912658261884u128;
+        let b = 38188712660835962328561942614081743514u128;
+        let c = 61331686004747624160469066397670963925u128;
+
+        let xs = (
+            ExtF127::from((a, b)),
+            ExtF127::from((b, c)),
+            ExtF127::from((c, 0)),
+            ExtF127::from((0, a)),
+        );
+
+        let x_vec: ExtF127x4 = xs.into();
+
+        let z: (ExtF127, ExtF127, ExtF127, ExtF127) = (x_vec * x_vec.invert()).into();
+
+        let one = ExtF127::from((1, 0));
+        assert_eq!(z, (one, one, one, one));
+    }
+
+    #[test]
+    fn x2_ops_match_serial() {
+        let a = 43654918112560223727172090912658261884u128;
+        let b = 38188712660835962328561942614081743514u128;
+        let c = 61331686004747624160469066397670963925u128;
+
+        let xs = (ExtF127::from((a, b)), ExtF127::from((c, 0)));
+        let ys = (ExtF127::from((b, c)), ExtF127::from((0, a)));
+
+        let x_vec: ExtF127x2 = xs.into();
+        let y_vec: ExtF127x2 = ys.into();
+
+        let product: (ExtF127, ExtF127) = (x_vec * y_vec).into();
+        assert_eq!(product, (xs.0 * ys.0, xs.1 * ys.1));
+
+        let sum: (ExtF127, ExtF127) = (x_vec + (-y_vec)).into();
+        assert_eq!(sum, (xs.0 - ys.0, xs.1 - ys.1));
+
+        let chained: (ExtF127, ExtF127) = (x_vec * y_vec * x_vec + y_vec).into();
+        assert_eq!(
+            chained,
+            (xs.0 * ys.0 * xs.0 + ys.0, xs.1 * ys.1 * xs.1 + ys.1)
+        );
+
+        assert_eq!(x_vec.extract(0), xs.0);
+        assert_eq!(x_vec.extract(1), xs.1);
+        assert_eq!(ExtF127x2::splat(ys.1).extract(0), ys.1);
+    }
+
+    #[test]
+    fn x2_invert_gives_inverse() {
+        let a = 43654918112560223727172090912658261884u128;
+        let b = 38188712660835962328561942614081743514u128;
+
+        let x_vec = ExtF127x2::from((ExtF127::from((a, b)), ExtF127::from((0, a))));
+
+        let z: (ExtF127, ExtF127) = (x_vec * x_vec.invert()).into();
+
+        let one = ExtF127::from((1, 0));
+        assert_eq!(z, (one, one));
+
+        let zero = ExtF127::from((0, 0));
+        let z: (ExtF127, ExtF127) = ExtF127x2::from((zero, zero)).invert().into();
+        assert_eq!(z, (zero, zero));
+    }
+
+    #[test]
+    fn precomputed_mul_matches_serial() {
+        let a = 43654918112560223727172090912658261884u128;
+        let b = 38188712660835962328561942614081743514u128;
+        let c = 61331686004747624160469066397670963925u128;
+
+        let xs = (
+            ExtF127::from((a, b)),
+            ExtF127::from((b, c)),
+            ExtF127::from((c, 0)),
+            ExtF127::from((0, a)),
+        );
+        let y = ExtF127::from((c, a));
+
+        let x_vec: ExtF127x4 = xs.into();
+        let y_pre = PrecomputedExtF127x4::new(ExtF127x4::splat(y));
+
+        let zs: (ExtF127, ExtF127, ExtF127, ExtF127) = (x_vec * y_pre).into();
+
+        assert_eq!(zs, (xs.0 * y, xs.1 * y, xs.2 * y, xs.3 * y));
+    }
 }
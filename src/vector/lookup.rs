@@ -0,0 +1,144 @@
+//! Constant-time table lookups.
+//!
+//! Windowed scalar multiplication needs to fetch table entry \\(i\\)
+//! for a secret \\(i\\).  Rather than indexing the table, these
+//! functions scan the whole table, and use a masked blend on every
+//! limb to keep the entry whose position matches the index, so that
+//! neither the memory access pattern nor the instruction trace
+//! depends on the index.
+
+use crate::simd::{m64x4, u64x4};
+
+use super::ExtF127x4;
+use crate::serial::{ExtF127, F127};
+
+/// Returns a mask with every lane set if `x == y`, without branching.
+#[inline]
+fn equal_mask(x: usize, y: usize) -> m64x4 {
+    u64x4::splat(x as u64).eq(u64x4::splat(y as u64))
+}
+
+/// Returns `(|x|, mask)`, where every lane of `mask` is set if
+/// `x < 0`, without branching.
+#[inline]
+fn abs_and_sign_mask(x: isize) -> (usize, m64x4) {
+    let sign = x >> (8 * core::mem::size_of::<isize>() - 1);
+    // This wraps for `isize::MIN`, whose absolute value is still out of
+    // range for any table.
+    let abs = (x ^ sign).wrapping_sub(sign) as usize;
+    (abs, u64x4::splat(sign as u64).eq(u64x4::splat(u64::MAX)))
+}
+
+/// Implements constant-time selection from a table of vectors, where
+/// `$zero` is the serial element used for out-of-range indices.
+macro_rules! impl_select {
+    ($F:ty, $zero:expr) => {
+        impl $F {
+            /// Select `table[index]` in constant time.
+            ///
+            /// Every entry of the table is read, regardless of `index`.  If
+            /// `index` is out of range, the result is zero.
+            pub fn select(table: &[$F], index: usize) -> $F {
+                let mut result = <$F>::splat($zero);
+                for (j, entry) in table.iter().enumerate() {
+                    result = result.blend(*entry, equal_mask(j, index));
+                }
+                result
+            }
+
+            /// Select \\(\operatorname{sign}(i) \cdot\\) `table[|i|]` in
+            /// constant time, where \\(i\\) is `index`.
+            ///
+            /// Every entry of the table is read, regardless of `index`.  If
+            /// `|index|` is out of range, the result is zero.
+            pub fn select_signed(table: &[$F], index: isize) -> $F {
+                let (abs, negative) = abs_and_sign_mask(index);
+                let x = <$F>::select(table, abs);
+                x.blend(-x, negative)
+            }
+        }
+    };
+}
+
+#[cfg(target_feature = "avx512ifma")]
+impl_select!(super::prime_field::F127x4, F127::zero());
+impl_select!(super::avx2::F127x4, F127::zero());
+impl_select!(ExtF127x4, ExtF127(F127::zero(), F127::zero()));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector::F127x4;
+
+    type Lanes = (ExtF127, ExtF127, ExtF127, ExtF127);
+
+    fn table() -> Vec<Lanes> {
+        let mut x = ExtF127::from((
+            43654918112560223727172090912658261884u128,
+            38188712660835962328561942614081743514u128,
+        ));
+        let y = ExtF127::from((
+            38188712660835962328561942614081743514u128,
+            61331686004747624160469066397670963925u128,
+        ));
+
+        (0..16)
+            .map(|_| {
+                x = x * y;
+                (x, x + x, x * x, x + y)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn select_returns_each_entry() {
+        let table = table();
+        let vec_table: Vec<ExtF127x4> = table.iter().map(|x| (*x).into()).collect();
+        let re_table: Vec<F127x4> = table
+            .iter()
+            .map(|x| ((x.0).0, (x.1).0, (x.2).0, (x.3).0).into())
+            .collect();
+
+        for (i, &x) in table.iter().enumerate() {
+            let y: Lanes = ExtF127x4::select(&vec_table, i).into();
+            assert_eq!(y, x);
+
+            let y: (F127, F127, F127, F127) = F127x4::select(&re_table, i).into();
+            assert_eq!(y, ((x.0).0, (x.1).0, (x.2).0, (x.3).0));
+        }
+    }
+
+    #[test]
+    fn select_signed_negates() {
+        let table = table();
+        let vec_table: Vec<ExtF127x4> = table.iter().map(|x| (*x).into()).collect();
+        let neg = |x: ExtF127| ExtF127(-x.0, -x.1);
+
+        // Skip the zeroth entry, since -0 = 0.
+        for (i, &x) in table.iter().enumerate().skip(1) {
+            let y: Lanes = ExtF127x4::select_signed(&vec_table, i as isize).into();
+            let neg_y: Lanes = ExtF127x4::select_signed(&vec_table, -(i as isize)).into();
+
+            assert_eq!(y, x);
+            assert_eq!(neg_y, (neg(x.0), neg(x.1), neg(x.2), neg(x.3)));
+        }
+    }
+
+    #[test]
+    fn select_out_of_range_is_zero() {
+        let table = table();
+        let vec_table: Vec<ExtF127x4> = table.iter().map(|x| (*x).into()).collect();
+        let zero = ExtF127(F127::zero(), F127::zero());
+
+        let y: Lanes = ExtF127x4::select(&vec_table, table.len()).into();
+        assert_eq!(y, (zero, zero, zero, zero));
+
+        let y: Lanes = ExtF127x4::select_signed(&vec_table, -(table.len() as isize)).into();
+        assert_eq!(y, (zero, zero, zero, zero));
+
+        for &i in &[isize::MIN, isize::MAX] {
+            let y: Lanes = ExtF127x4::select_signed(&vec_table, i).into();
+            assert_eq!(y, (zero, zero, zero, zero));
+        }
+    }
+}
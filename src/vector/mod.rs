@@ -1,7 +1,27 @@
-#![doc(include = "../docs/vector.md")]
+#![doc = include_str!("../../docs/vector.md")]
 
+pub mod avx2;
+#[cfg(target_feature = "avx512ifma")]
+mod bounds;
+mod exponentiation;
 mod ext_field;
+mod lookup;
+#[cfg(target_feature = "avx512ifma")]
 mod prime_field;
 
-pub use self::ext_field::ExtF127x4;
-pub use self::prime_field::F127x4;
+pub use crate::simd::m64x4;
+
+#[cfg(target_feature = "avx512ifma")]
+pub use self::ext_field::ExtF127x8;
+pub use self::ext_field::{ExtF127x2, ExtF127x4, PackedExtF127x4, PrecomputedExtF127x4};
+
+// Use the IFMA backend when it's available, and the AVX2 backend
+// otherwise.
+#[cfg(not(target_feature = "avx512ifma"))]
+pub(crate) use self::avx2::LAZY_PRODUCTS;
+#[cfg(not(target_feature = "avx512ifma"))]
+pub use self::avx2::{F127x4, PackedF127x4, PrecomputedF127x4};
+#[cfg(target_feature = "avx512ifma")]
+pub(crate) use self::prime_field::LAZY_PRODUCTS;
+#[cfg(target_feature = "avx512ifma")]
+pub use self::prime_field::{F127x4, F127x8, PackedF127x4, PrecomputedF127x4};
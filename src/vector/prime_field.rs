@@ -1,150 +1,377 @@
-//! Vectorized prime-field arithmetic.
+//! Vectorized prime-field arithmetic using IFMA.
 
 use core::ops::{Add, Mul, Neg};
 
-use packed_simd::u64x4;
+use crate::simd::{m64x4, u64x4, u64x8};
 
-use crate::ifma::{madd52hi, madd52lo};
+use crate::ifma::{madd52hi, madd52hi_512, madd52lo, madd52lo_512};
 
 use crate::serial::F127;
 
+use super::bounds::Bounds;
+
+/// The number of products which can be added to a reduced `F127x4`
+/// before it must be reduced again.
+///
+/// The limbs of a product are less than \\(2^{44}\\), and those of
+/// a reduced element at most \\(2^{43}\\), so the sum of this many
+/// products stays below \\(2^{63}\\), leaving room for the carries
+/// in `reduce`.
+pub(crate) const LAZY_PRODUCTS: usize = (1 << 19) - 1;
+
+/// A vector of four elements of the Mersenne field.
+#[derive(Copy, Clone)]
+pub struct F127x4(u64x4, u64x4, u64x4, Bounds);
+
+/// A compact storage form of an `F127x4`.
+///
+/// An `F127x4` occupies three 256-bit registers, but a reduced
+/// element only needs 127 bits per lane.  This stores each lane in
+/// two 64-bit words instead, so that large tables of precomputed
+/// values are a third smaller, and lookups touch fewer cache lines.
+///
+/// Use `F127x4::pack` and `PackedF127x4::unpack` to convert to and
+/// from the form used for arithmetic.
+#[derive(Copy, Clone)]
+pub struct PackedF127x4(u64x4, u64x4);
+
+/// A vector of four elements of the Mersenne field, prepared for use
+/// as a fixed multiplier.
+///
+/// Products \\(x_i y_j\\) with \\(i + j \geq 3\\) wrap around to
+/// the low limbs with a factor of \\(4\\).  A general multiplication
+/// applies that factor (and the differing weights of the low and high
+/// halves) by shifting its accumulators, but here the limbs of \\(4y\\)
+/// are stored alongside those of \\(y\\), so that multiplication needs
+/// fewer shifts and has six independent chains of IFMA operations
+/// with uniform weights.  Since the stored limbs are reduced, the
+/// other operand may have limbs of up to \\(52\\) bits.
+///
+/// This is useful for curve constants, and for any operand which is
+/// used for several multiplications.
+#[derive(Copy, Clone)]
+pub struct PrecomputedF127x4([u64x4; 3], [u64x4; 3], Bounds);
+
+/// A vector of eight elements of the Mersenne field.
+///
+/// This uses the full 512-bit IFMA instructions, which execute at
+/// full width on Ice Lake and later, and on Zen 4.
 #[derive(Copy, Clone)]
-pub struct F127x4(u64x4, u64x4, u64x4);
+pub struct F127x8(u64x8, u64x8, u64x8, Bounds);
 
 impl From<(F127, F127, F127, F127)> for F127x4 {
     fn from(x: (F127, F127, F127, F127)) -> F127x4 {
-        let low_43_bits = (1 << 43) - 1;
+        // Gather the two 64-bit words of each element, then split them
+        // into limbs with vector shifts, as in `PackedF127x4::unpack`.
+        let lo = u64x4::new(
+            (x.0).0 as u64,
+            (x.1).0 as u64,
+            (x.2).0 as u64,
+            (x.3).0 as u64,
+        );
+        let hi = u64x4::new(
+            ((x.0).0 >> 64) as u64,
+            ((x.1).0 >> 64) as u64,
+            ((x.2).0 >> 64) as u64,
+            ((x.3).0 >> 64) as u64,
+        );
+
+        let mask43 = u64x4::splat((1 << 43) - 1);
+        let mask22 = u64x4::splat((1 << 22) - 1);
 
         F127x4(
-            u64x4::new(
-                ((x.0).0 & low_43_bits) as u64,
-                ((x.1).0 & low_43_bits) as u64,
-                ((x.2).0 & low_43_bits) as u64,
-                ((x.3).0 & low_43_bits) as u64,
-            ),
-            u64x4::new(
-                (((x.0).0 >> 43) & low_43_bits) as u64,
-                (((x.1).0 >> 43) & low_43_bits) as u64,
-                (((x.2).0 >> 43) & low_43_bits) as u64,
-                (((x.3).0 >> 43) & low_43_bits) as u64,
-            ),
-            u64x4::new(
-                (((x.0).0 >> 86) & low_43_bits) as u64,
-                (((x.1).0 >> 86) & low_43_bits) as u64,
-                (((x.2).0 >> 86) & low_43_bits) as u64,
-                (((x.3).0 >> 86) & low_43_bits) as u64,
-            ),
+            lo & mask43,
+            (lo >> 43) | ((hi & mask22) << 21),
+            hi >> 22,
+            Bounds::reduced(),
         )
     }
 }
 
-impl Into<(F127, F127, F127, F127)> for F127x4 {
-    fn into(mut self) -> (F127, F127, F127, F127) {
-        // We store self as a 129-bit (or more) integer,
+impl From<F127x4> for (F127, F127, F127, F127) {
+    fn from(mut x: F127x4) -> (F127, F127, F127, F127) {
+        // We store x as a 129-bit (or more) integer,
         // so reduce it before conversion
-        // XXX check bounds
+        x.3.check_convertible();
         let mask = u64x4::splat((1 << 41) - 1);
-        let c = self.2 >> 41;
-        self.0 += c;
-        self.2 &= mask;
+        let c = x.2 >> 41;
+        x.0 += c;
+        x.2 &= mask;
 
         (
             F127::from(
-                (self.0.extract(0) as u128)
-                    + ((self.1.extract(0) as u128) << 43)
-                    + ((self.2.extract(0) as u128) << 86),
+                (x.0.extract(0) as u128)
+                    + ((x.1.extract(0) as u128) << 43)
+                    + ((x.2.extract(0) as u128) << 86),
             ),
             F127::from(
-                (self.0.extract(1) as u128)
-                    + ((self.1.extract(1) as u128) << 43)
-                    + ((self.2.extract(1) as u128) << 86),
+                (x.0.extract(1) as u128)
+                    + ((x.1.extract(1) as u128) << 43)
+                    + ((x.2.extract(1) as u128) << 86),
             ),
             F127::from(
-                (self.0.extract(2) as u128)
-                    + ((self.1.extract(2) as u128) << 43)
-                    + ((self.2.extract(2) as u128) << 86),
+                (x.0.extract(2) as u128)
+                    + ((x.1.extract(2) as u128) << 43)
+                    + ((x.2.extract(2) as u128) << 86),
             ),
             F127::from(
-                (self.0.extract(3) as u128)
-                    + ((self.1.extract(3) as u128) << 43)
-                    + ((self.2.extract(3) as u128) << 86),
+                (x.0.extract(3) as u128)
+                    + ((x.1.extract(3) as u128) << 43)
+                    + ((x.2.extract(3) as u128) << 86),
             ),
         )
     }
 }
 
-impl Neg for F127x4 {
-    type Output = Self;
+impl F127x4 {
+    /// Weakly reduce the limbs of `self`, so that the low limb has at
+    /// most 43 bits, the middle limb is at most \\(2^{43}\\), and
+    /// the high limb has at most 41 bits.
+    #[inline]
+    pub fn reduce(self) -> F127x4 {
+        self.3.check_reducible();
+        let mask43 = u64x4::splat((1 << 43) - 1);
+        let mask41 = u64x4::splat((1 << 41) - 1);
+
+        let (mut l0, mut l1, mut l2) = (self.0, self.1, self.2);
+
+        l1 += l0 >> 43;
+        l0 &= mask43;
+        l2 += l1 >> 43;
+        l1 &= mask43;
+        // Since 2^127 = 1 (mod p), the carry out of the high limb
+        // wraps around to the low limb.
+        l0 += l2 >> 41;
+        l2 &= mask41;
+        // The wraparound carry is small, so after this step l0 has at
+        // most 43 bits and l1 is at most 2^43.
+        l1 += l0 >> 43;
+        l0 &= mask43;
+
+        F127x4(
+            l0,
+            l1,
+            l2,
+            Bounds::new([(1 << 43) - 1, 1 << 43, (1 << 41) - 1]),
+        )
+    }
+
+    /// Construct a vector with `x` in every lane.
+    #[inline]
+    pub fn splat(x: F127) -> F127x4 {
+        (x, x, x, x).into()
+    }
+
+    /// Extract the element in the given `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub fn extract(self, lane: usize) -> F127 {
+        self.3.check_convertible();
+        let l0 = self.0.extract(lane) as u128;
+        let l1 = self.1.extract(lane) as u128;
+        let l2 = self.2.extract(lane) as u128;
+
+        // As in the conversion to serial elements, reduce the high limb
+        F127::from((l0 + (l2 >> 41)) + (l1 << 43) + ((l2 & ((1 << 41) - 1)) << 86))
+    }
+
+    /// Return a copy of `self` with the element in the given `lane`
+    /// replaced by `x`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[inline]
+    pub fn insert(self, lane: usize, x: F127) -> F127x4 {
+        let low_43_bits = (1 << 43) - 1;
+
+        F127x4(
+            self.0.replace(lane, (x.0 & low_43_bits) as u64),
+            self.1.replace(lane, ((x.0 >> 43) & low_43_bits) as u64),
+            self.2.replace(lane, ((x.0 >> 86) & low_43_bits) as u64),
+            self.3.max(Bounds::reduced()),
+        )
+    }
+
+    /// Permute the lanes of `self` according to `PATTERN`.
+    ///
+    /// Lane \\(i\\) of the result is lane `(PATTERN >> 2*i) & 3` of
+    /// `self`, as for `vpermq`.  For instance, to map
+    /// \\((X, Y, Z, T)\\) to \\((Y, X, T, Z)\\), use
+    /// `x.shuffle::<0b10_11_00_01>()`.
+    ///
+    /// This is a single permutation instruction on each limb.
+    #[inline]
+    pub fn shuffle<const PATTERN: i32>(self) -> F127x4 {
+        F127x4(
+            self.0.permute::<PATTERN>(),
+            self.1.permute::<PATTERN>(),
+            self.2.permute::<PATTERN>(),
+            self.3,
+        )
+    }
+
+    /// Return a vector whose lanes are taken from `other` where `mask`
+    /// is set, and from `self` where it is not.
+    ///
+    /// This is a single blend instruction on each limb.
     #[inline]
-    fn neg(self) -> Self {
+    pub fn blend(self, other: F127x4, mask: m64x4) -> F127x4 {
         F127x4(
-            u64x4::splat(((1 << 43) - 1) << 3) - self.0,
-            u64x4::splat(((1 << 43) - 1) << 3) - self.1,
-            u64x4::splat(((1 << 41) - 1) << 3) - self.2,
+            mask.select(other.0, self.0),
+            mask.select(other.1, self.1),
+            mask.select(other.2, self.2),
+            self.3.max(other.3),
         )
     }
+
+    /// Return a mask of the lanes where `self` and `other` are equal
+    /// as field elements.
+    ///
+    /// Both operands are canonicalized first, so this compares values
+    /// rather than limbs, and it runs in constant time.
+    #[inline]
+    pub fn ct_eq(self, other: F127x4) -> m64x4 {
+        let (x, y) = (self.canonical_limbs(), other.canonical_limbs());
+        x[0].eq(y[0]) & x[1].eq(y[1]) & x[2].eq(y[2])
+    }
+
+    /// Return a mask of the lanes where `self` is zero, in constant
+    /// time.
+    #[inline]
+    pub fn is_zero(self) -> m64x4 {
+        let x = self.canonical_limbs();
+        let zero = u64x4::splat(0);
+        x[0].eq(zero) & x[1].eq(zero) & x[2].eq(zero)
+    }
+
+    /// Compute the limbs of the canonical representative of each lane,
+    /// in \\([0, p)\\).
+    #[inline]
+    fn canonical_limbs(self) -> [u64x4; 3] {
+        let x = self.reduce();
+        let mask43 = u64x4::splat((1 << 43) - 1);
+        let mask41 = u64x4::splat((1 << 41) - 1);
+
+        let (mut l0, mut l1, mut l2) = (x.0, x.1, x.2);
+
+        // After reduction only the middle limb can exceed its radix,
+        // and only by one, so one more carry pass leaves every limb
+        // within its radix.  The wraparound carry into the low limb
+        // only happens when the high limb becomes zero, so the carries
+        // out of it cannot propagate past the high limb again.
+        l2 += l1 >> 43;
+        l1 &= mask43;
+        l0 += l2 >> 41;
+        l2 &= mask41;
+        l1 += l0 >> 43;
+        l0 &= mask43;
+        l2 += l1 >> 43;
+        l1 &= mask43;
+
+        // Now each lane is in [0, p], and p has every bit set.
+        let is_p = l0.eq(mask43) & l1.eq(mask43) & l2.eq(mask41);
+        let zero = u64x4::splat(0);
+
+        [
+            is_p.select(zero, l0),
+            is_p.select(zero, l1),
+            is_p.select(zero, l2),
+        ]
+    }
+
+    /// Convert `self` to its compact storage form.
+    #[inline]
+    pub fn pack(self) -> PackedF127x4 {
+        let x = self.reduce();
+
+        // After reduction, x < 2^128 in every lane, and the limbs can
+        // be repacked into two 64-bit words, lo + hi * 2^64.  The
+        // middle limb straddles the words, and since it may have 44
+        // bits, its top part is added (not or-ed) to the high word.
+        let lo = x.0 | (x.1 << 43);
+        let hi = (x.1 >> 21) + (x.2 << 22);
+
+        PackedF127x4(lo, hi)
+    }
 }
 
-impl Add<F127x4> for F127x4 {
-    type Output = F127x4;
+impl PackedF127x4 {
+    /// Convert `self` to the radix-\\(2^{43}\\) form used for arithmetic.
     #[inline]
-    fn add(self, other: F127x4) -> F127x4 {
-        F127x4(self.0 + other.0, self.1 + other.1, self.2 + other.2)
+    pub fn unpack(self) -> F127x4 {
+        let mask43 = u64x4::splat((1 << 43) - 1);
+        let mask22 = u64x4::splat((1 << 22) - 1);
+
+        let (lo, hi) = (self.0, self.1);
+
+        F127x4(
+            lo & mask43,
+            (lo >> 43) | ((hi & mask22) << 21),
+            hi >> 22,
+            Bounds::new([(1 << 43) - 1, (1 << 43) - 1, (1 << 42) - 1]),
+        )
+    }
+}
+
+impl PrecomputedF127x4 {
+    /// Prepare `y` for use as a fixed multiplier.
+    pub fn new(y: F127x4) -> PrecomputedF127x4 {
+        let y = y.reduce();
+
+        PrecomputedF127x4([y.0, y.1, y.2], [y.0 << 2, y.1 << 2, y.2 << 2], y.3)
     }
 }
 
-impl Mul<F127x4> for F127x4 {
+impl Mul<PrecomputedF127x4> for F127x4 {
     type Output = F127x4;
     #[inline]
-    fn mul(self, other: F127x4) -> F127x4 {
-        let (x0, y0) = (self.0, other.0);
-        let (x1, y1) = (self.1, other.1);
-        let (x2, y2) = (self.2, other.2);
-
-        // We have 18 multiplications, want 8 independent chains to
-        // saturate the EUs, so split into 9 chains of length 2.
-
-        let mut z0_a = u64x4::splat(0);
-        let mut z0_b = u64x4::splat(0);
-        let mut z0_c = u64x4::splat(0);
-        let mut z1_a = u64x4::splat(0);
-        let mut z1_b = u64x4::splat(0);
-        let mut z1_c = u64x4::splat(0);
-        let mut z2_a = u64x4::splat(0);
-        let mut z2_b = u64x4::splat(0);
-        let mut z2_c = u64x4::splat(0);
-
-        z0_a = madd52hi(z0_a, x2, y0); // 2^11
-        z0_b = madd52lo(z0_b, x2, y1); // 2^2
-        z0_c = madd52hi(z0_c, x1, y1); // 2^11
-
-        z1_a = madd52hi(z1_a, x0, y0); // 2^9
-        z1_b = madd52hi(z1_b, x2, y1); // 2^11
-        z1_c = madd52lo(z1_c, x1, y0); // 2^0
-
-        z2_a = madd52hi(z2_a, x2, y2); // 2^11
-        z2_b = madd52hi(z2_b, x0, y1); // 2^9
-        z2_c = madd52lo(z2_c, x2, y0); // 2^0
-
-        z0_a = z0_a << 11; // 2^11 -> 2^0
-        z1_a = z1_a << 07; // 2^9  -> 2^2
-        z2_a = z2_a << 11; // 2^11 -> 2^0
-
-        z0_a = madd52lo(z0_a, x0, y0); // 2^0
-        z0_b = madd52lo(z0_b, x1, y2); // 2^2
-        z0_c = madd52hi(z0_c, x0, y2); // 2^11
-
-        z1_a = madd52lo(z1_a, x2, y2); // 2^2
-        z1_b = madd52hi(z1_b, x1, y2); // 2^11
-        z1_c = madd52lo(z1_c, x0, y1); // 2^0
-
-        z2_a = madd52lo(z2_a, x0, y2); // 2^0
-        z2_b = madd52hi(z2_b, x1, y0); // 2^9
-        z2_c = madd52lo(z2_c, x1, y1); // 2^0
-
-        let z0 = z0_a + (z0_b << 2) + (z0_c << 11);
-        let z1 = (z1_a << 2) + (z1_b << 11) + z1_c;
-        let z2 = z2_a + (z2_b << 9) + z2_c;
+    fn mul(self, other: PrecomputedF127x4) -> F127x4 {
+        let bounds = self.3.mul_precomputed(other.2);
+
+        let (x0, x1, x2) = (self.0, self.1, self.2);
+        let [y0, y1, y2] = other.0;
+        let [_, w1, w2] = other.1;
+
+        // Terms landing at 2^(43*3) and above wrap around with a
+        // factor of 4, which is already applied in w = 4y.  The high
+        // halves of the terms at 2^(43*2) also wrap around, and get a
+        // shift of 2^11 instead of 2^9.
+        let zero = u64x4::splat(0);
+
+        // SAFETY: this module is only compiled when the IFMA target
+        // features are enabled for the whole crate.
+        let (z0, z1, z2) = unsafe {
+            let mut z0_lo = madd52lo(zero, x0, y0);
+            let mut z0_hi = madd52hi(zero, x2, y0); // 2^11
+            let mut z1_lo = madd52lo(zero, x1, y0);
+            let mut z1_hi = madd52hi(zero, x0, y0); // 2^9
+            let mut z2_lo = madd52lo(zero, x2, y0);
+            let mut z2_hi = madd52hi(zero, x1, y0); // 2^9
+
+            z0_lo = madd52lo(z0_lo, x2, w1);
+            z0_hi = madd52hi(z0_hi, x1, y1);
+            z1_lo = madd52lo(z1_lo, x0, y1);
+            z1_hi = madd52hi(z1_hi, x2, w1);
+            z2_lo = madd52lo(z2_lo, x1, y1);
+            z2_hi = madd52hi(z2_hi, x0, y1);
+
+            z0_lo = madd52lo(z0_lo, x1, w2);
+            z0_hi = madd52hi(z0_hi, x0, y2);
+            z1_lo = madd52lo(z1_lo, x2, w2);
+            z1_hi = madd52hi(z1_hi, x1, w2);
+            z2_lo = madd52lo(z2_lo, x0, y2);
+            z2_hi = madd52hi(z2_hi, x2, w2);
+
+            (
+                z0_lo + (z0_hi << 11),
+                z1_lo + (z1_hi << 9),
+                z2_lo + (z2_hi << 9),
+            )
+        };
 
         let c0 = z0 >> 43;
         let c1 = z1 >> 43;
@@ -152,10 +379,219 @@ impl Mul<F127x4> for F127x4 {
 
         let mask = u64x4::splat((1 << 43) - 1);
 
-        F127x4((z0 & mask) + (c2 << 2), (z1 & mask) + c0, (z2 & mask) + c1)
+        F127x4(
+            (z0 & mask) + (c2 << 2),
+            (z1 & mask) + c0,
+            (z2 & mask) + c1,
+            bounds,
+        )
+    }
+}
+
+impl From<[F127; 8]> for F127x8 {
+    fn from(x: [F127; 8]) -> F127x8 {
+        let low_43_bits = (1 << 43) - 1;
+
+        let mut limbs = [[0u64; 8]; 3];
+        for (j, xj) in x.iter().enumerate() {
+            limbs[0][j] = (xj.0 & low_43_bits) as u64;
+            limbs[1][j] = ((xj.0 >> 43) & low_43_bits) as u64;
+            limbs[2][j] = ((xj.0 >> 86) & low_43_bits) as u64;
+        }
+
+        F127x8(
+            u64x8::from(limbs[0]),
+            u64x8::from(limbs[1]),
+            u64x8::from(limbs[2]),
+            Bounds::reduced(),
+        )
+    }
+}
+
+impl From<F127x8> for [F127; 8] {
+    fn from(mut x: F127x8) -> [F127; 8] {
+        // As for F127x4, reduce the high limb before conversion
+        x.3.check_convertible();
+        let mask = u64x8::splat((1 << 41) - 1);
+        let c = x.2 >> 41;
+        x.0 += c;
+        x.2 &= mask;
+
+        let mut z = [F127::zero(); 8];
+        for (j, zj) in z.iter_mut().enumerate() {
+            *zj = F127::from(
+                (x.0.extract(j) as u128)
+                    + ((x.1.extract(j) as u128) << 43)
+                    + ((x.2.extract(j) as u128) << 86),
+            );
+        }
+        z
     }
 }
 
+/// Implements negation, addition, and multiplication for a vector of
+/// field elements in radix \\(2^{43}\\), using the given IFMA intrinsics.
+macro_rules! impl_arithmetic {
+    ($F:ident, $u64xN:ident, $madd52lo:ident, $madd52hi:ident) => {
+        impl Neg for $F {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                const P_TIMES_8: [u64; 3] = [
+                    ((1 << 43) - 1) << 3,
+                    ((1 << 43) - 1) << 3,
+                    ((1 << 41) - 1) << 3,
+                ];
+                $F(
+                    $u64xN::splat(P_TIMES_8[0]) - self.0,
+                    $u64xN::splat(P_TIMES_8[1]) - self.1,
+                    $u64xN::splat(P_TIMES_8[2]) - self.2,
+                    self.3.sub_from(P_TIMES_8),
+                )
+            }
+        }
+
+        impl Add<$F> for $F {
+            type Output = $F;
+            #[inline]
+            fn add(self, other: $F) -> $F {
+                $F(
+                    self.0 + other.0,
+                    self.1 + other.1,
+                    self.2 + other.2,
+                    self.3.add(other.3),
+                )
+            }
+        }
+
+        impl $F {
+            /// Compute the products `x.0 * x.1` and `y.0 * y.1`.
+            ///
+            /// The two multiplications are independent, so interleaving
+            /// them gives the processor twice as many chains of IFMA
+            /// operations to schedule, and overlaps the carries at the
+            /// end of one with the work of the other.
+            #[inline]
+            pub fn mul2(x: ($F, $F), y: ($F, $F)) -> ($F, $F) {
+                let bounds = ((x.0).3.mul((x.1).3), (y.0).3.mul((y.1).3));
+
+                // SAFETY: see `products`.
+                let (z, w) = unsafe { ($F::products(x.0, x.1), $F::products(y.0, y.1)) };
+
+                ($F::carry(z, bounds.0), $F::carry(w, bounds.1))
+            }
+
+            /// Compute `x.0 * x.1 + y.0 * y.1`.
+            ///
+            /// This interleaves the two multiplications like `mul2`,
+            /// but sums their accumulators before carrying, so that
+            /// only one carry step is needed.
+            #[inline]
+            pub fn mul_add2(x: ($F, $F), y: ($F, $F)) -> $F {
+                let bounds = Bounds::mul_add2(((x.0).3, (x.1).3), ((y.0).3, (y.1).3));
+
+                // SAFETY: see `products`.
+                let (z, w) = unsafe { ($F::products(x.0, x.1), $F::products(y.0, y.1)) };
+
+                $F::carry((z.0 + w.0, z.1 + w.1, z.2 + w.2), bounds)
+            }
+
+            /// Compute the accumulators of the product of `x` and `y`,
+            /// before carrying.
+            ///
+            /// This module is only compiled when the IFMA target
+            /// features are enabled for the whole crate, so this can
+            /// always be called safely.
+            #[inline]
+            #[target_feature(enable = "avx512ifma,avx512vl")]
+            fn products(x: $F, y: $F) -> ($u64xN, $u64xN, $u64xN) {
+                let (x0, y0) = (x.0, y.0);
+                let (x1, y1) = (x.1, y.1);
+                let (x2, y2) = (x.2, y.2);
+
+                // We have 18 multiplications, want 8 independent chains to
+                // saturate the EUs, so split into 9 chains of length 2.
+
+                let mut z0_a = $u64xN::splat(0);
+                let mut z0_b = $u64xN::splat(0);
+                let mut z0_c = $u64xN::splat(0);
+                let mut z1_a = $u64xN::splat(0);
+                let mut z1_b = $u64xN::splat(0);
+                let mut z1_c = $u64xN::splat(0);
+                let mut z2_a = $u64xN::splat(0);
+                let mut z2_b = $u64xN::splat(0);
+                let mut z2_c = $u64xN::splat(0);
+
+                z0_a = $madd52hi(z0_a, x2, y0); // 2^11
+                z0_b = $madd52lo(z0_b, x2, y1); // 2^2
+                z0_c = $madd52hi(z0_c, x1, y1); // 2^11
+
+                z1_a = $madd52hi(z1_a, x0, y0); // 2^9
+                z1_b = $madd52hi(z1_b, x2, y1); // 2^11
+                z1_c = $madd52lo(z1_c, x1, y0); // 2^0
+
+                z2_a = $madd52hi(z2_a, x2, y2); // 2^11
+                z2_b = $madd52hi(z2_b, x0, y1); // 2^9
+                z2_c = $madd52lo(z2_c, x2, y0); // 2^0
+
+                z0_a <<= 11; // 2^11 -> 2^0
+                z1_a <<= 7; // 2^9  -> 2^2
+                z2_a <<= 11; // 2^11 -> 2^0
+
+                z0_a = $madd52lo(z0_a, x0, y0); // 2^0
+                z0_b = $madd52lo(z0_b, x1, y2); // 2^2
+                z0_c = $madd52hi(z0_c, x0, y2); // 2^11
+
+                z1_a = $madd52lo(z1_a, x2, y2); // 2^2
+                z1_b = $madd52hi(z1_b, x1, y2); // 2^11
+                z1_c = $madd52lo(z1_c, x0, y1); // 2^0
+
+                z2_a = $madd52lo(z2_a, x0, y2); // 2^0
+                z2_b = $madd52hi(z2_b, x1, y0); // 2^9
+                z2_c = $madd52lo(z2_c, x1, y1); // 2^0
+
+                let z0 = z0_a + (z0_b << 2) + (z0_c << 11);
+                let z1 = (z1_a << 2) + (z1_b << 11) + z1_c;
+                let z2 = z2_a + (z2_b << 9) + z2_c;
+
+                (z0, z1, z2)
+            }
+
+            /// Carry the accumulators `z` of a product into limbs.
+            #[inline]
+            fn carry(z: ($u64xN, $u64xN, $u64xN), bounds: Bounds) -> $F {
+                let (z0, z1, z2) = z;
+
+                let c0 = z0 >> 43;
+                let c1 = z1 >> 43;
+                let c2 = z2 >> 43;
+
+                let mask = $u64xN::splat((1 << 43) - 1);
+
+                $F(
+                    (z0 & mask) + (c2 << 2),
+                    (z1 & mask) + c0,
+                    (z2 & mask) + c1,
+                    bounds,
+                )
+            }
+        }
+
+        impl Mul<$F> for $F {
+            type Output = $F;
+            #[inline]
+            fn mul(self, other: $F) -> $F {
+                let bounds = self.3.mul(other.3);
+                // SAFETY: see `products`.
+                $F::carry(unsafe { $F::products(self, other) }, bounds)
+            }
+        }
+    };
+}
+
+impl_arithmetic!(F127x4, u64x4, madd52lo, madd52hi);
+impl_arithmetic!(F127x8, u64x8, madd52lo_512, madd52hi_512);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,4 +651,234 @@ mod tests {
         assert_eq!(zs.3, F127::zero());
     }
 
+    #[test]
+    fn ct_eq_compares_values() {
+        let xs: (F127, F127, F127, F127) = (
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+        );
+        let x_vec: F127x4 = xs.into();
+
+        // The same values, with different limbs.
+        let y_vec = (x_vec + x_vec) + (-x_vec);
+        assert_eq!(x_vec.ct_eq(y_vec), m64x4::splat(true));
+
+        let z_vec = x_vec.insert(2, xs.0);
+        assert_eq!(x_vec.ct_eq(z_vec), m64x4::new(true, true, false, true));
+
+        assert_eq!(x_vec.is_zero(), m64x4::splat(false));
+        assert_eq!((x_vec + (-x_vec)).is_zero(), m64x4::splat(true));
+
+        // p itself is all ones in every limb, and 8p is -0.
+        let p = F127((1 << 127) - 1);
+        let zero = F127::zero();
+        let w_vec = F127x4::from((p, zero, xs.1, zero)) + (-F127x4::splat(zero));
+        assert_eq!(w_vec.is_zero(), m64x4::new(true, true, false, true));
+        assert_eq!(
+            w_vec.ct_eq(F127x4::splat(zero)),
+            m64x4::new(true, true, false, true)
+        );
+    }
+
+    #[test]
+    fn mul2_and_mul_add2_match_serial() {
+        let xs: (F127, F127, F127, F127) = (
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+        );
+        let a: F127x4 = xs.into();
+        let b = a.shuffle::<0b00_11_10_01>();
+        let c = a.shuffle::<0b01_00_11_10>();
+        let d = a + a;
+
+        let (ab, cd) = F127x4::mul2((a, b), (c, d));
+        let sum = F127x4::mul_add2((a, b), (c, d));
+
+        let expected_ab: (F127, F127, F127, F127) = (a * b).into();
+        let expected_cd: (F127, F127, F127, F127) = (c * d).into();
+        let expected_sum: (F127, F127, F127, F127) = (a * b + c * d).into();
+
+        assert_eq!(expected_ab, ab.into());
+        assert_eq!(expected_cd, cd.into());
+        assert_eq!(expected_sum, sum.into());
+        assert_eq!(expected_sum.1, xs.1 * xs.2 + xs.3 * (xs.1 + xs.1));
+    }
+
+    #[test]
+    fn mul_accepts_eight_bits_of_excess() {
+        let x = F127::from(101054725971136791246222244709531340474u128);
+        let x_vec: F127x4 = (x, x, x, x).into();
+
+        // Summing 2^8 copies gives limbs of up to 51 bits.
+        let mut y = F127::zero();
+        let mut y_vec = x_vec;
+        for _ in 0..256 {
+            y = y + x;
+        }
+        for _ in 0..255 {
+            y_vec = y_vec + x_vec;
+        }
+
+        let zs: (F127, F127, F127, F127) = (y_vec * y_vec).into();
+
+        assert_eq!(zs.0, y * y);
+        assert_eq!(zs.3, y * y);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "exceeds 52 bits")]
+    fn mul_rejects_too_many_unreduced_additions() {
+        let x = F127::from(101054725971136791246222244709531340474u128);
+        let x_vec: F127x4 = (x, x, x, x).into();
+
+        let mut y_vec = x_vec;
+        for _ in 0..599 {
+            y_vec = y_vec + x_vec;
+        }
+
+        let _ = y_vec * y_vec;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "reduction overflows a 64-bit limb")]
+    fn reduce_rejects_carry_overflow() {
+        // The high limb fits in 64 bits, but the carry out of the middle
+        // limb does not fit on top of it.
+        let limbs = [(1 << 43) - 1, u64::MAX, u64::MAX - (1 << 20)];
+        let x = F127x4(
+            u64x4::splat(limbs[0]),
+            u64x4::splat(limbs[1]),
+            u64x4::splat(limbs[2]),
+            Bounds::new(limbs),
+        );
+
+        let _ = x.reduce();
+    }
+
+    #[test]
+    fn x8_mul_matches_serial() {
+        let xs: [F127; 8] = [
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+            146218790534899613207529930246486884549u128.into(),
+            153545699326103250302240116789699702227u128.into(),
+            169667241773565003665316522159863431654u128.into(),
+            78832676377202070809965755704023168971u128.into(),
+        ];
+
+        let x_vec: F127x8 = xs.into();
+
+        let zs: [F127; 8] = (x_vec * x_vec + (-x_vec)).into();
+
+        for j in 0..8 {
+            assert_eq!(zs[j], xs[j] * xs[j] - xs[j]);
+        }
+    }
+
+    #[test]
+    fn pack_unpack_round_trips() {
+        let xs: (F127, F127, F127, F127) = (
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+        );
+
+        let x_vec: F127x4 = xs.into();
+
+        // Check inputs with both large and reduced limbs.
+        let mut y = xs;
+        let mut y_vec = x_vec;
+        for _ in 0..100 {
+            y = (y.0 + xs.0, y.1 + xs.1, y.2 + xs.2, y.3 + xs.3);
+            y_vec = y_vec + x_vec;
+        }
+        let z = (y.0 * y.0, y.1 * y.1, y.2 * y.2, y.3 * y.3);
+        let z_vec = y_vec * y_vec;
+
+        let packed_xs: (F127, F127, F127, F127) = x_vec.pack().unpack().into();
+        let packed_ys: (F127, F127, F127, F127) = y_vec.pack().unpack().into();
+        let packed_zs: (F127, F127, F127, F127) = z_vec.pack().unpack().into();
+        let negated: (F127, F127, F127, F127) = (-z_vec).pack().unpack().into();
+
+        assert_eq!(packed_xs, xs);
+        assert_eq!(packed_ys, y);
+        assert_eq!(packed_zs, z);
+        assert_eq!(negated, (-z.0, -z.1, -z.2, -z.3));
+    }
+
+    #[test]
+    fn lane_operations_match_serial() {
+        let xs: (F127, F127, F127, F127) = (
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+        );
+        let y = F127::from(146218790534899613207529930246486884549u128);
+
+        let x_vec: F127x4 = xs.into();
+
+        let shuffled: (F127, F127, F127, F127) = x_vec.shuffle::<0b10_11_00_01>().into();
+        assert_eq!(shuffled, (xs.1, xs.0, xs.3, xs.2));
+
+        let broadcast: (F127, F127, F127, F127) = x_vec.shuffle::<0b10_10_10_10>().into();
+        assert_eq!(broadcast, (xs.2, xs.2, xs.2, xs.2));
+
+        let splat: (F127, F127, F127, F127) = F127x4::splat(y).into();
+        assert_eq!(splat, (y, y, y, y));
+
+        let mask = m64x4::new(false, true, true, false);
+        let blended: (F127, F127, F127, F127) = x_vec.blend(F127x4::splat(y), mask).into();
+        assert_eq!(blended, (xs.0, y, y, xs.3));
+
+        let inserted: (F127, F127, F127, F127) = x_vec.insert(2, y).into();
+        assert_eq!(inserted, (xs.0, xs.1, y, xs.3));
+
+        // Extracting reduces the lane, so use an unreduced product
+        let z_vec = x_vec * x_vec;
+        assert_eq!(z_vec.extract(0), xs.0 * xs.0);
+        assert_eq!(z_vec.extract(1), xs.1 * xs.1);
+        assert_eq!(z_vec.extract(2), xs.2 * xs.2);
+        assert_eq!(z_vec.extract(3), xs.3 * xs.3);
+    }
+
+    #[test]
+    fn precomputed_mul_matches_serial() {
+        let xs: (F127, F127, F127, F127) = (
+            101054725971136791246222244709531340474u128.into(),
+            38188712660835962328561942614081743514u128.into(),
+            43654918112560223727172090912658261884u128.into(),
+            61331686004747624160469066397670963925u128.into(),
+        );
+        let y = F127::from(146218790534899613207529930246486884549u128);
+
+        let x_vec: F127x4 = xs.into();
+        let y_pre = PrecomputedF127x4::new(F127x4::splat(y) * F127x4::splat(y));
+
+        let zs: (F127, F127, F127, F127) = (x_vec * y_pre).into();
+        assert_eq!(zs, (xs.0 * y * y, xs.1 * y * y, xs.2 * y * y, xs.3 * y * y));
+
+        // The other operand may have limbs of up to 52 bits, which is
+        // more than a general multiplication accepts: sum 2^9 copies.
+        let mut big = F127::zero();
+        let mut big_vec = x_vec;
+        for _ in 0..512 {
+            big = big + xs.1;
+        }
+        for _ in 0..511 {
+            big_vec = big_vec + x_vec;
+        }
+
+        let zs: (F127, F127, F127, F127) = (big_vec * y_pre).into();
+        assert_eq!(zs.1, big * y * y);
+    }
 }